@@ -0,0 +1,1026 @@
+//! Solidity ABI 编解码
+//!
+//! 函数选择器为 Keccak-256(规范签名) 的前4字节，如 `transfer(address,uint256)`。
+//! 参数按 ABI 规范的头尾方式编码：静态类型直接写入头部，动态类型（`bytes`、`string`、
+//! `T[]` 以及包含动态成员的定长数组和元组）在头部写入相对偏移，内容写在尾部。
+//! 片段使用标准 JSON ABI 格式（`type`、`name`、`inputs`、`outputs`，元组带 `components`）。
+//!
+//! 编码输入与解码输出均为 JSON：地址为校验和字符串，整数为十进制字符串（编码时也接受数字和
+//! 0x十六进制字符串），`bytes` 为0x十六进制，数组为 JSON 数组，元组在成员均有名称时为对象，
+//! 否则为数组。
+
+use serde_json::{Map, Value};
+use web3::types::U256;
+
+use crate::chain;
+use crate::ethereum::{self, encode_integer, keccak256};
+
+/// `Error(string)` 的选择器
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+/// `Panic(uint256)` 的选择器
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+/// 类型的最大嵌套层数，解析和解码时都检查
+const MAX_DEPTH: usize = 32;
+
+/// 参数类型
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParamType {
+    Address,
+    Bool,
+    Uint(usize),
+    Int(usize),
+    FixedBytes(usize),
+    Bytes,
+    String,
+    Array(Box<ParamType>),
+    FixedArray(Box<ParamType>, usize),
+    Tuple(Vec<Param>),
+}
+
+/// 带名称的参数，名称可为空
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Param {
+    pub name: String,
+    pub kind: ParamType,
+}
+
+/// 函数或自定义错误片段
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fragment {
+    pub kind: FragmentKind,
+    pub name: String,
+    pub inputs: Vec<Param>,
+    pub outputs: Vec<Param>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FragmentKind {
+    Function,
+    Error,
+}
+
+/// 解码出的参数
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedParam {
+    pub name: String,
+    /// 规范类型，如 `uint256`、`(address,uint256)[]`
+    pub kind: String,
+    pub value: Value,
+}
+
+/// 回滚原因
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Revert {
+    /// 无返回数据（`revert()` 或 `require(cond)`）
+    Empty,
+    /// `Error(string)`，即 `require(cond, "message")` 和 `revert("message")`
+    Error(String),
+    /// `Panic(uint256)`，编译器插入的检查失败
+    Panic(U256),
+    /// ABI 中声明的自定义错误
+    Custom {
+        fragment: Fragment,
+        args: Vec<DecodedParam>,
+    },
+    /// 选择器未知或数据不足4字节
+    Unknown(Vec<u8>),
+}
+
+impl ParamType {
+    /// 解析类型字符串：基本类型、`T[]`、`T[n]` 和元组 `(T1,T2)`；`tuple` 的成员由 `components` 给出
+    pub fn parse(kind: &str, components: Option<&[Param]>) -> Result<Self, String> {
+        ParamType::parse_at(kind, components, 0)
+    }
+
+    /// 每层数组和内联元组的成员深度加一
+    fn parse_at(kind: &str, components: Option<&[Param]>, depth: usize) -> Result<Self, String> {
+        check_depth(depth)?;
+        let kind = kind.trim();
+        if let Some(body) = kind.strip_suffix(']') {
+            let open = body
+                .rfind('[')
+                .ok_or_else(|| format!("Invalid array type: '{}'", kind))?;
+            let element = Box::new(ParamType::parse_at(&body[..open], components, depth + 1)?);
+            let length = &body[open + 1..];
+            if length.is_empty() {
+                return Ok(ParamType::Array(element));
+            }
+            return length
+                .parse()
+                .map(|length| ParamType::FixedArray(element, length))
+                .map_err(|_| format!("Invalid array length in type '{}'", kind));
+        }
+        if kind == "tuple" {
+            return components
+                .map(|components| ParamType::Tuple(components.to_vec()))
+                .ok_or_else(|| "Tuple type is missing components".to_string());
+        }
+        let tuple = kind.strip_prefix("tuple").unwrap_or(kind);
+        if let Some(members) = tuple.strip_prefix('(').and_then(|k| k.strip_suffix(')')) {
+            return split_types(members)?
+                .into_iter()
+                .map(|member| {
+                    ParamType::parse_at(&param_type(member), None, depth + 1).map(|kind| Param {
+                        name: String::new(),
+                        kind,
+                    })
+                })
+                .collect::<Result<_, _>>()
+                .map(ParamType::Tuple);
+        }
+
+        match kind {
+            "address" => Ok(ParamType::Address),
+            "bool" => Ok(ParamType::Bool),
+            "string" => Ok(ParamType::String),
+            "bytes" => Ok(ParamType::Bytes),
+            "uint" => Ok(ParamType::Uint(256)),
+            "int" => Ok(ParamType::Int(256)),
+            _ => {
+                if let Some(size) = kind.strip_prefix("bytes") {
+                    parse_size(kind, size, 1, 32).map(ParamType::FixedBytes)
+                } else if let Some(bits) = kind.strip_prefix("uint") {
+                    parse_bits(kind, bits).map(ParamType::Uint)
+                } else if let Some(bits) = kind.strip_prefix("int") {
+                    parse_bits(kind, bits).map(ParamType::Int)
+                } else {
+                    Err(format!("Unknown ABI type: '{}'", kind))
+                }
+            }
+        }
+    }
+
+    /// 规范类型名，用于签名
+    pub fn canonical(&self) -> String {
+        match self {
+            ParamType::Address => "address".to_string(),
+            ParamType::Bool => "bool".to_string(),
+            ParamType::Uint(bits) => format!("uint{}", bits),
+            ParamType::Int(bits) => format!("int{}", bits),
+            ParamType::FixedBytes(size) => format!("bytes{}", size),
+            ParamType::Bytes => "bytes".to_string(),
+            ParamType::String => "string".to_string(),
+            ParamType::Array(element) => format!("{}[]", element.canonical()),
+            ParamType::FixedArray(element, length) => {
+                format!("{}[{}]", element.canonical(), length)
+            }
+            ParamType::Tuple(members) => format!("({})", canonical_list(members)),
+        }
+    }
+
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            ParamType::Bytes | ParamType::String | ParamType::Array(_) => true,
+            ParamType::FixedArray(element, _) => element.is_dynamic(),
+            ParamType::Tuple(members) => members.iter().any(|member| member.kind.is_dynamic()),
+            _ => false,
+        }
+    }
+
+    /// 在头部占用的字节数：动态类型为32字节偏移，静态定长数组和元组按成员展开；溢出时为 None
+    fn head_size(&self) -> Option<usize> {
+        match self {
+            _ if self.is_dynamic() => Some(32),
+            ParamType::FixedArray(element, length) => element.head_size()?.checked_mul(*length),
+            ParamType::Tuple(members) => members.iter().try_fold(0usize, |size, member| {
+                size.checked_add(member.kind.head_size()?)
+            }),
+            _ => Some(32),
+        }
+    }
+}
+
+impl Param {
+    /// 解析 JSON ABI 中的参数 `{name, type, components}`
+    fn from_json(value: &Value) -> Result<Self, String> {
+        Param::from_json_at(value, 0)
+    }
+
+    fn from_json_at(value: &Value, depth: usize) -> Result<Self, String> {
+        check_depth(depth)?;
+        let kind = value
+            .get("type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| "ABI parameter is missing 'type'".to_string())?;
+        let components = match value.get("components") {
+            Some(Value::Array(components)) => {
+                // 成员位于元组本身和类型后缀中的每层数组之内
+                let depth = depth + kind.matches('[').count() + 1;
+                Some(
+                    components
+                        .iter()
+                        .map(|component| Param::from_json_at(component, depth))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )
+            }
+            _ => None,
+        };
+        Ok(Param {
+            name: value
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            kind: ParamType::parse_at(kind, components.as_deref(), depth)?,
+        })
+    }
+}
+
+impl Fragment {
+    /// 解析单个 JSON ABI 片段，`type` 缺省为 function
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| format!("Invalid ABI JSON: {}", e))?;
+        Fragment::from_value(&value)?
+            .ok_or_else(|| "ABI fragment must be a function or an error".to_string())
+    }
+
+    /// 函数和错误以外的片段（事件、构造函数等）返回 None
+    fn from_value(value: &Value) -> Result<Option<Self>, String> {
+        let kind = match value.get("type").and_then(Value::as_str) {
+            None | Some("function") => FragmentKind::Function,
+            Some("error") => FragmentKind::Error,
+            Some(_) => return Ok(None),
+        };
+        let name = value
+            .get("name")
+            .and_then(Value::as_str)
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| "ABI fragment is missing 'name'".to_string())?;
+        let params = |key: &str| match value.get(key) {
+            Some(Value::Array(params)) => params.iter().map(Param::from_json).collect(),
+            Some(_) => Err(format!("ABI fragment '{}' must be an array", key)),
+            None => Ok(Vec::new()),
+        };
+
+        Ok(Some(Fragment {
+            kind,
+            name: name.to_string(),
+            inputs: params("inputs")?,
+            outputs: params("outputs")?,
+        }))
+    }
+
+    /// 规范签名，如 `transfer(address,uint256)`
+    pub fn signature(&self) -> String {
+        format!("{}({})", self.name, canonical_list(&self.inputs))
+    }
+
+    pub fn selector(&self) -> [u8; 4] {
+        selector(&self.signature())
+    }
+
+    /// 编码调用数据：选择器 + 参数，`args` 为按顺序的数组或以参数名为键的对象
+    pub fn encode_call(&self, args: &Value) -> Result<Vec<u8>, String> {
+        let mut calldata = self.selector().to_vec();
+        calldata.extend(encode_params(&self.inputs, args)?);
+        Ok(calldata)
+    }
+
+    /// 解码调用数据中的参数，选择器必须与片段一致
+    pub fn decode_call(&self, calldata: &[u8]) -> Result<Vec<DecodedParam>, String> {
+        match calldata.get(..4) {
+            Some(prefix) if prefix == self.selector() => {
+                decode_params(&self.inputs, &calldata[4..])
+            }
+            Some(prefix) => Err(format!(
+                "Selector mismatch: expected 0x{} for {}, got 0x{}",
+                hex::encode(self.selector()),
+                self.signature(),
+                hex::encode(prefix)
+            )),
+            None => Err("Calldata is shorter than 4 bytes".to_string()),
+        }
+    }
+
+    /// 解码函数返回数据
+    pub fn decode_output(&self, data: &[u8]) -> Result<Vec<DecodedParam>, String> {
+        decode_params(&self.outputs, data)
+    }
+}
+
+/// 解析 JSON ABI：完整的 ABI 数组或单个片段，只保留函数和错误
+pub fn parse_abi(json: &str) -> Result<Vec<Fragment>, String> {
+    let value: Value =
+        serde_json::from_str(json).map_err(|e| format!("Invalid ABI JSON: {}", e))?;
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| Fragment::from_value(item).transpose())
+            .collect(),
+        value => Ok(Fragment::from_value(&value)?.into_iter().collect()),
+    }
+}
+
+/// 按选择器在 ABI 中查找函数，解码调用数据
+pub fn decode_call<'a>(
+    abi: &'a [Fragment],
+    calldata: &[u8],
+) -> Result<(&'a Fragment, Vec<DecodedParam>), String> {
+    let prefix = calldata
+        .get(..4)
+        .ok_or_else(|| "Calldata is shorter than 4 bytes".to_string())?;
+    let fragment = abi
+        .iter()
+        .filter(|fragment| fragment.kind == FragmentKind::Function)
+        .find(|fragment| fragment.selector() == prefix)
+        .ok_or_else(|| {
+            format!(
+                "No function in ABI matches selector 0x{}",
+                hex::encode(prefix)
+            )
+        })?;
+    Ok((fragment, fragment.decode_call(calldata)?))
+}
+
+/// 签名字符串的选择器：Keccak-256 的前4字节
+pub fn selector(signature: &str) -> [u8; 4] {
+    let mut selector = [0u8; 4];
+    selector.copy_from_slice(&keccak256(signature.as_bytes())[..4]);
+    selector
+}
+
+/// 解析人工书写的签名（如 `transfer(address to, uint amount)`）并返回规范签名
+pub fn canonical_signature(signature: &str) -> Result<String, String> {
+    let signature = signature.trim();
+    let signature = signature
+        .strip_prefix("function ")
+        .or_else(|| signature.strip_prefix("error "))
+        .unwrap_or(signature)
+        .trim();
+    let open = signature
+        .find('(')
+        .ok_or_else(|| format!("Invalid function signature: '{}'", signature))?;
+    let params = signature[open..]
+        .strip_suffix(')')
+        .map(|params| &params[1..])
+        .ok_or_else(|| format!("Invalid function signature: '{}'", signature))?;
+    let name = signature[..open].trim();
+    if name.is_empty() {
+        return Err(format!("Invalid function signature: '{}'", signature));
+    }
+
+    let kinds = split_types(params)?
+        .into_iter()
+        .map(|param| ParamType::parse(&param_type(param), None).map(|kind| kind.canonical()))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(format!("{}({})", name, kinds.join(",")))
+}
+
+/// 解码回滚数据；`abi` 用于匹配自定义错误
+pub fn decode_revert(data: &[u8], abi: &[Fragment]) -> Result<Revert, String> {
+    let (prefix, body) = match data.len() {
+        0 => return Ok(Revert::Empty),
+        1..=3 => return Ok(Revert::Unknown(data.to_vec())),
+        _ => data.split_at(4),
+    };
+
+    if prefix == ERROR_SELECTOR {
+        let message = decode_sequence(&[&ParamType::String], body, 0)?.0.remove(0);
+        return Ok(Revert::Error(
+            message.as_str().unwrap_or_default().to_string(),
+        ));
+    }
+    if prefix == PANIC_SELECTOR {
+        return Ok(Revert::Panic(U256::from_big_endian(read_word(body, 0)?)));
+    }
+    match abi
+        .iter()
+        .filter(|fragment| fragment.kind == FragmentKind::Error)
+        .find(|fragment| fragment.selector() == prefix)
+    {
+        Some(fragment) => Ok(Revert::Custom {
+            fragment: fragment.clone(),
+            args: decode_params(&fragment.inputs, body)?,
+        }),
+        None => Ok(Revert::Unknown(data.to_vec())),
+    }
+}
+
+/// `Panic(uint256)` 错误码的含义
+pub fn panic_reason(code: U256) -> &'static str {
+    if code.bits() > 8 {
+        return "unknown panic code";
+    }
+    match code.low_u32() {
+        0x00 => "generic compiler panic",
+        0x01 => "assertion failed",
+        0x11 => "arithmetic overflow or underflow",
+        0x12 => "division or modulo by zero",
+        0x21 => "invalid enum value",
+        0x22 => "invalid storage byte array encoding",
+        0x31 => "pop on empty array",
+        0x32 => "array index out of bounds",
+        0x41 => "out of memory",
+        0x51 => "call to uninitialized internal function",
+        _ => "unknown panic code",
+    }
+}
+
+/// 编码参数列表，`args` 为数组或以参数名为键的对象
+pub fn encode_params(params: &[Param], args: &Value) -> Result<Vec<u8>, String> {
+    let values = match args {
+        Value::Null if params.is_empty() => Vec::new(),
+        Value::Array(values) if values.len() == params.len() => values.iter().collect(),
+        Value::Array(values) => {
+            return Err(format!(
+                "Expected {} arguments, got {}",
+                params.len(),
+                values.len()
+            ));
+        }
+        Value::Object(fields) => named_values(params, fields)?,
+        _ => return Err("Arguments must be an array or an object".to_string()),
+    };
+
+    let items: Vec<(&ParamType, &Value)> =
+        params.iter().map(|param| &param.kind).zip(values).collect();
+    encode_sequence(&items)
+}
+
+/// 解码参数列表
+pub fn decode_params(params: &[Param], data: &[u8]) -> Result<Vec<DecodedParam>, String> {
+    let kinds: Vec<&ParamType> = params.iter().map(|param| &param.kind).collect();
+    Ok(params
+        .iter()
+        .zip(decode_sequence(&kinds, data, 0)?.0)
+        .map(|(param, value)| DecodedParam {
+            name: param.name.clone(),
+            kind: param.kind.canonical(),
+            value,
+        })
+        .collect())
+}
+
+fn canonical_list(params: &[Param]) -> String {
+    params
+        .iter()
+        .map(|param| param.kind.canonical())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// 去掉参数名和数据位置（如 `uint256 amount`、`bytes calldata data`），只保留类型
+fn param_type(param: &str) -> String {
+    let param = param.trim();
+    match param.rfind(')') {
+        Some(close) => {
+            let suffix = param[close + 1..].split_whitespace().next().unwrap_or("");
+            format!("{}{}", &param[..=close], suffix)
+        }
+        None => param.split_whitespace().next().unwrap_or("").to_string(),
+    }
+}
+
+/// 按最外层逗号拆分类型列表，括号内的逗号不拆分
+fn split_types(list: &str) -> Result<Vec<&str>, String> {
+    if list.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut parts = Vec::new();
+    let (mut depth, mut start) = (0usize, 0);
+    for (i, c) in list.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| format!("Unbalanced parentheses in '{}'", list))?;
+            }
+            ',' if depth == 0 => {
+                parts.push(list[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(format!("Unbalanced parentheses in '{}'", list));
+    }
+    parts.push(list[start..].trim());
+    Ok(parts)
+}
+
+fn parse_size(kind: &str, size: &str, min: usize, max: usize) -> Result<usize, String> {
+    match size.parse() {
+        Ok(parsed) if (min..=max).contains(&parsed) && !size.starts_with('0') => Ok(parsed),
+        _ => Err(format!("Unknown ABI type: '{}'", kind)),
+    }
+}
+
+/// 整数位宽为 8 到 256 之间 8 的倍数
+fn parse_bits(kind: &str, bits: &str) -> Result<usize, String> {
+    match parse_size(kind, bits, 8, 256)? {
+        bits if bits % 8 == 0 => Ok(bits),
+        _ => Err(format!("Unknown ABI type: '{}'", kind)),
+    }
+}
+
+/// 以参数名为键取值，元组成员须全部有名称
+fn named_values<'a>(
+    params: &[Param],
+    fields: &'a Map<String, Value>,
+) -> Result<Vec<&'a Value>, String> {
+    params
+        .iter()
+        .map(|param| {
+            if param.name.is_empty() {
+                return Err("Unnamed parameters must be passed as an array".to_string());
+            }
+            fields
+                .get(&param.name)
+                .ok_or_else(|| format!("Missing argument '{}'", param.name))
+        })
+        .collect()
+}
+
+/// 头尾编码：静态成员写入头部，动态成员在头部写偏移（相对于序列起点），内容追加到尾部
+///
+/// 头部大小来自类型（如 `uint256[99999999999]`）而非值，不能用来预分配。
+fn encode_sequence(items: &[(&ParamType, &Value)]) -> Result<Vec<u8>, String> {
+    let head_size = sequence_head_size(items.iter().map(|(kind, _)| *kind))?;
+    let mut head = Vec::new();
+    let mut tail = Vec::new();
+    for (kind, value) in items {
+        let encoded = encode_value(kind, value)?;
+        if kind.is_dynamic() {
+            head.extend_from_slice(&usize_word(head_size + tail.len()));
+            tail.extend(encoded);
+        } else {
+            head.extend(encoded);
+        }
+    }
+    head.extend(tail);
+    Ok(head)
+}
+
+fn encode_value(kind: &ParamType, value: &Value) -> Result<Vec<u8>, String> {
+    match kind {
+        ParamType::Address => {
+            let text = value
+                .as_str()
+                .ok_or_else(|| "Address must be a string".to_string())?;
+            ethereum::validate_address(text, false, None)?;
+            let mut word = [0u8; 32];
+            word[12..].copy_from_slice(&decode_hex(value)?);
+            Ok(word.to_vec())
+        }
+        ParamType::Bool => match value {
+            Value::Bool(flag) => Ok(usize_word(usize::from(*flag)).to_vec()),
+            _ => Err("Value of type 'bool' must be a boolean".to_string()),
+        },
+        ParamType::Uint(bits) => Ok(encode_integer(value, *bits, false)?.to_vec()),
+        ParamType::Int(bits) => Ok(encode_integer(value, *bits, true)?.to_vec()),
+        ParamType::FixedBytes(size) => {
+            let bytes = decode_hex(value)?;
+            if bytes.len() != *size {
+                return Err(format!(
+                    "Value of type 'bytes{}' must be {} bytes, got {}",
+                    size,
+                    size,
+                    bytes.len()
+                ));
+            }
+            let mut word = [0u8; 32];
+            word[..*size].copy_from_slice(&bytes);
+            Ok(word.to_vec())
+        }
+        ParamType::Bytes => Ok(encode_bytes(&decode_hex(value)?)),
+        ParamType::String => value
+            .as_str()
+            .map(|text| encode_bytes(text.as_bytes()))
+            .ok_or_else(|| "Value of type 'string' must be a string".to_string()),
+        ParamType::Array(element) => {
+            let values = value
+                .as_array()
+                .ok_or_else(|| format!("Value of type '{}' must be an array", kind.canonical()))?;
+            let items: Vec<_> = values
+                .iter()
+                .map(|value| (element.as_ref(), value))
+                .collect();
+            let mut encoded = usize_word(values.len()).to_vec();
+            encoded.extend(encode_sequence(&items)?);
+            Ok(encoded)
+        }
+        ParamType::FixedArray(element, length) => {
+            let values = value
+                .as_array()
+                .filter(|values| values.len() == *length)
+                .ok_or_else(|| {
+                    format!(
+                        "Value of type '{}' must be an array of {} elements",
+                        kind.canonical(),
+                        length
+                    )
+                })?;
+            let items: Vec<_> = values
+                .iter()
+                .map(|value| (element.as_ref(), value))
+                .collect();
+            encode_sequence(&items)
+        }
+        ParamType::Tuple(members) => encode_params(members, value),
+    }
+}
+
+/// `bytes` 和 `string`：长度 + 右侧补零到32字节整数倍的内容
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut encoded = usize_word(bytes.len()).to_vec();
+    encoded.extend_from_slice(bytes);
+    encoded.resize(32 + bytes.len().div_ceil(32) * 32, 0);
+    encoded
+}
+
+/// 序列头部的总字节数
+fn sequence_head_size<'a>(mut kinds: impl Iterator<Item = &'a ParamType>) -> Result<usize, String> {
+    kinds
+        .try_fold(0usize, |size, kind| size.checked_add(kind.head_size()?))
+        .ok_or_else(|| "ABI head size overflows".to_string())
+}
+
+/// 数组元素在头部占用的字节数，必须非零：长度只能由剩余数据限制
+fn element_size(array: &ParamType, element: &ParamType) -> Result<usize, String> {
+    match element.head_size() {
+        Some(0) => Err(format!(
+            "Unsupported zero-sized array element in {}",
+            array.canonical()
+        )),
+        Some(size) => Ok(size),
+        None => Err(format!("ABI head size overflows in {}", array.canonical())),
+    }
+}
+
+fn check_depth(depth: usize) -> Result<(), String> {
+    if depth > MAX_DEPTH {
+        return Err(format!("ABI type nesting exceeds {} levels", MAX_DEPTH));
+    }
+    Ok(())
+}
+
+fn usize_word(value: usize) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&(value as u64).to_be_bytes());
+    word
+}
+
+fn decode_hex(value: &Value) -> Result<Vec<u8>, String> {
+    let text = value
+        .as_str()
+        .ok_or_else(|| "Bytes value must be a hex string".to_string())?;
+    chain::decode_hex(text).map_err(|e| format!("Invalid hex value: {}", e))
+}
+
+fn read_word(data: &[u8], offset: usize) -> Result<&[u8], String> {
+    offset
+        .checked_add(32)
+        .and_then(|end| data.get(offset..end))
+        .ok_or_else(|| format!("Data too short: need 32 bytes at offset {}", offset))
+}
+
+/// 读取偏移或长度，不得超出数据长度
+fn read_usize(data: &[u8], offset: usize) -> Result<usize, String> {
+    let value = U256::from_big_endian(read_word(data, offset)?);
+    if value > U256::from(data.len()) {
+        return Err(format!("Offset or length {} exceeds data length", value));
+    }
+    Ok(value.as_usize())
+}
+
+/// 解码头尾编码的序列，`data` 从序列起点开始，返回各值和序列占用的字节数
+///
+/// 动态成员的内容必须从头部和前一个动态成员内容的末尾之后开始，不得重叠。否则多个偏移可以
+/// 指向同一段数据，嵌套数组的解码量随层数指数增长，而调用数据和回滚数据都不可信。
+fn decode_sequence(
+    kinds: &[&ParamType],
+    data: &[u8],
+    depth: usize,
+) -> Result<(Vec<Value>, usize), String> {
+    check_depth(depth)?;
+    let mut head = 0usize;
+    let mut end = sequence_head_size(kinds.iter().copied())?;
+    let mut values = Vec::with_capacity(kinds.len());
+    for kind in kinds {
+        let value = if kind.is_dynamic() {
+            let offset = read_usize(data, head)?;
+            if offset < end {
+                return Err(format!(
+                    "Offset {} overlaps data decoded before offset {}",
+                    offset, end
+                ));
+            }
+            let (value, size) = decode_value(kind, &data[offset..], depth + 1)?;
+            end = offset.saturating_add(size);
+            value
+        } else {
+            decode_value(kind, data.get(head..).unwrap_or_default(), depth + 1)?.0
+        };
+        values.push(value);
+        // 各成员头部之和已在上面检查过不溢出
+        head += kind.head_size().unwrap_or_default();
+    }
+    Ok((values, end))
+}
+
+/// 解码单个值，返回值和占用的字节数（动态类型为内容的长度）
+fn decode_value(kind: &ParamType, data: &[u8], depth: usize) -> Result<(Value, usize), String> {
+    match kind {
+        ParamType::Address => {
+            let word = read_word(data, 0)?;
+            if word[..12].iter().any(|b| *b != 0) {
+                return Err("Address has non-zero padding".to_string());
+            }
+            let address = <[u8; 20]>::try_from(&word[12..]).unwrap_or_default();
+            Ok((
+                Value::String(ethereum::to_checksum_address(&address, None)),
+                32,
+            ))
+        }
+        ParamType::Bool => match U256::from_big_endian(read_word(data, 0)?) {
+            flag if flag <= U256::one() => Ok((Value::Bool(!flag.is_zero()), 32)),
+            _ => Err("Bool must be 0 or 1".to_string()),
+        },
+        ParamType::Uint(bits) => {
+            let value = U256::from_big_endian(read_word(data, 0)?);
+            if value.bits() > *bits {
+                return Err(format!("Value out of range for uint{}", bits));
+            }
+            Ok((Value::String(value.to_string()), 32))
+        }
+        ParamType::Int(bits) => {
+            let word = U256::from_big_endian(read_word(data, 0)?);
+            let (negative, magnitude) = if word.bit(255) {
+                (true, (!word).overflowing_add(U256::one()).0)
+            } else {
+                (false, word)
+            };
+            let in_range = if negative {
+                magnitude <= U256::one() << (bits - 1)
+            } else {
+                magnitude.bits() < *bits
+            };
+            if !in_range {
+                return Err(format!("Value out of range for int{}", bits));
+            }
+            let sign = if negative { "-" } else { "" };
+            Ok((Value::String(format!("{}{}", sign, magnitude)), 32))
+        }
+        ParamType::FixedBytes(size) => {
+            let word = read_word(data, 0)?;
+            if word[*size..].iter().any(|b| *b != 0) {
+                return Err(format!("bytes{} has non-zero padding", size));
+            }
+            Ok((
+                Value::String(format!("0x{}", hex::encode(&word[..*size]))),
+                32,
+            ))
+        }
+        ParamType::Bytes => {
+            let bytes = read_bytes(data)?;
+            let size = 32 + bytes.len().div_ceil(32) * 32;
+            Ok((Value::String(format!("0x{}", hex::encode(bytes))), size))
+        }
+        ParamType::String => {
+            let bytes = read_bytes(data)?;
+            let size = 32 + bytes.len().div_ceil(32) * 32;
+            let text = String::from_utf8(bytes.to_vec())
+                .map_err(|_| "String is not valid UTF-8".to_string())?;
+            Ok((Value::String(text), size))
+        }
+        ParamType::Array(element) => {
+            let length = read_usize(data, 0)?;
+            // 每个元素在头部至少占32字节，长度不可能超过剩余数据
+            if length.saturating_mul(element_size(kind, element)?) > data.len() - 32 {
+                return Err(format!("Array length {} exceeds data length", length));
+            }
+            let kinds = vec![element.as_ref(); length];
+            let (values, size) = decode_sequence(&kinds, &data[32..], depth)?;
+            Ok((Value::Array(values), 32 + size))
+        }
+        ParamType::FixedArray(element, length) => {
+            if length.saturating_mul(element_size(kind, element)?) > data.len() {
+                return Err(format!("Data too short for {}", kind.canonical()));
+            }
+            let kinds = vec![element.as_ref(); *length];
+            let (values, size) = decode_sequence(&kinds, data, depth)?;
+            Ok((Value::Array(values), size))
+        }
+        ParamType::Tuple(members) => {
+            let kinds: Vec<&ParamType> = members.iter().map(|member| &member.kind).collect();
+            let (values, size) = decode_sequence(&kinds, data, depth)?;
+            let value = if members.is_empty() || members.iter().any(|member| member.name.is_empty())
+            {
+                Value::Array(values)
+            } else {
+                Value::Object(
+                    members
+                        .iter()
+                        .map(|member| member.name.clone())
+                        .zip(values)
+                        .collect(),
+                )
+            };
+            Ok((value, size))
+        }
+    }
+}
+
+/// 读取 `bytes`/`string` 的内容：32字节长度后接数据
+fn read_bytes(data: &[u8]) -> Result<&[u8], String> {
+    let length = read_usize(data, 0)?;
+    data.get(32..32 + length)
+        .ok_or_else(|| format!("Data too short for {} bytes", length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn word(value: u64) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        word[24..].copy_from_slice(&value.to_be_bytes());
+        word
+    }
+
+    fn words(hex_words: &[&str]) -> Vec<u8> {
+        hex_words
+            .iter()
+            .flat_map(|word| hex::decode(format!("{:0>64}", word)).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn encodes_solidity_docs_f() {
+        // Solidity 文档 ABI 规范中的 f(uint256,uint32[],bytes10,bytes) 示例
+        let fragment = Fragment::from_json(
+            r#"{"name":"f","inputs":[{"name":"a","type":"uint"},{"name":"b","type":"uint32[]"},{"name":"c","type":"bytes10"},{"name":"d","type":"bytes"}]}"#,
+        )
+        .unwrap();
+        let args = json!([
+            "0x123",
+            ["0x456", "0x789"],
+            "0x31323334353637383930",
+            "0x48656c6c6f2c20776f726c6421"
+        ]);
+        let mut expected = hex::decode("8be65246").unwrap();
+        expected.extend(words(&[
+            "123",
+            "80",
+            "3132333435363738393000000000000000000000000000000000000000000000",
+            "e0",
+            "2",
+            "456",
+            "789",
+            "d",
+            "48656c6c6f2c20776f726c642100000000000000000000000000000000000000",
+        ]));
+        assert_eq!(fragment.encode_call(&args).unwrap(), expected);
+
+        let decoded = fragment.decode_call(&expected).unwrap();
+        assert_eq!(decoded[0].value, json!("291"));
+        assert_eq!(decoded[1].value, json!(["1110", "1929"]));
+        assert_eq!(decoded[2].value, json!("0x31323334353637383930"));
+        assert_eq!(decoded[3].value, json!("0x48656c6c6f2c20776f726c6421"));
+    }
+
+    #[test]
+    fn encodes_solidity_docs_g() {
+        // Solidity 文档 ABI 规范中的 g(uint256[][],string[]) 示例
+        let fragment = Fragment::from_json(
+            r#"{"name":"g","inputs":[{"name":"a","type":"uint256[][]"},{"name":"b","type":"string[]"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(hex::encode(fragment.selector()), "2289b18c");
+        let args = json!([[[1, 2], [3]], ["one", "two", "three"]]);
+        let mut expected = fragment.selector().to_vec();
+        expected.extend(words(&[
+            "40",
+            "140",
+            "2",
+            "40",
+            "a0",
+            "2",
+            "1",
+            "2",
+            "1",
+            "3",
+            "3",
+            "60",
+            "a0",
+            "e0",
+            "3",
+            "6f6e650000000000000000000000000000000000000000000000000000000000",
+            "3",
+            "74776f0000000000000000000000000000000000000000000000000000000000",
+            "5",
+            "7468726565000000000000000000000000000000000000000000000000000000",
+        ]));
+        assert_eq!(fragment.encode_call(&args).unwrap(), expected);
+
+        let decoded = fragment.decode_call(&expected).unwrap();
+        assert_eq!(decoded[0].value, json!([["1", "2"], ["3"]]));
+        assert_eq!(decoded[1].value, json!(["one", "two", "three"]));
+    }
+
+    #[test]
+    fn decodes_error_string() {
+        let data = hex::decode(
+            "08c379a0\
+             0000000000000000000000000000000000000000000000000000000000000020\
+             000000000000000000000000000000000000000000000000000000000000000e\
+             4e6f7420617574686f72697a6564000000000000000000000000000000000000",
+        )
+        .unwrap();
+        assert_eq!(
+            decode_revert(&data, &[]).unwrap(),
+            Revert::Error("Not authorized".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_aliased_offsets() {
+        // 23层 uint8[]…[]：每层两个元素的偏移都指向同一个子数组，逐个解码需要 2^23 次
+        let kind = format!("uint8{}", "[]".repeat(23));
+        let fragment = Fragment::from_json(
+            &json!({"name": "f", "inputs": [{"name": "x", "type": kind}]}).to_string(),
+        )
+        .unwrap();
+        let mut calldata = fragment.selector().to_vec();
+        calldata.extend(word(32));
+        for _ in 0..22 {
+            calldata.extend(word(2));
+            calldata.extend(word(64));
+            calldata.extend(word(64));
+        }
+        calldata.extend(word(2));
+        calldata.extend(word(1));
+        calldata.extend(word(2));
+        assert_eq!(calldata.len(), 2244);
+
+        let error = fragment.decode_call(&calldata).unwrap_err();
+        assert!(error.contains("overlaps"), "{}", error);
+    }
+
+    #[test]
+    fn decodes_nested_dynamic_arrays() {
+        let fragment = Fragment::from_json(
+            r#"{"name":"f","inputs":[{"name":"x","type":"uint8[][][]"},{"name":"s","type":"string"}]}"#,
+        )
+        .unwrap();
+        let args = json!([[[[1, 2], [3]], [], [[]]], "abc"]);
+        let calldata = fragment.encode_call(&args).unwrap();
+        let decoded = fragment.decode_call(&calldata).unwrap();
+        assert_eq!(decoded[0].value, json!([[["1", "2"], ["3"]], [], [[]]]));
+        assert_eq!(decoded[1].value, json!("abc"));
+    }
+
+    #[test]
+    fn rejects_oversized_heads() {
+        // 头部大小超过地址空间时报错，而不是溢出或预分配
+        let fragment = Fragment::from_json(
+            r#"{"name":"f","inputs":[{"name":"x","type":"uint256[9223372036854775807]"}]}"#,
+        )
+        .unwrap();
+        let error = fragment.encode_call(&json!([[]])).unwrap_err();
+        assert!(error.contains("overflows"), "{}", error);
+        let error = fragment.decode_call(&fragment.selector()).unwrap_err();
+        assert!(error.contains("overflows"), "{}", error);
+
+        let fragment = Fragment::from_json(
+            r#"{"name":"f","inputs":[{"name":"x","type":"uint256[99999999999]"},{"name":"s","type":"string"}]}"#,
+        )
+        .unwrap();
+        assert!(fragment.encode_call(&json!([[], "abc"])).is_err());
+    }
+
+    #[test]
+    fn rejects_zero_sized_fixed_array_elements() {
+        let fragment = Fragment::from_json(
+            r#"{"name":"f","inputs":[{"name":"x","type":"tuple()[4294967295]"}]}"#,
+        )
+        .unwrap();
+        let error = fragment.decode_call(&fragment.selector()).unwrap_err();
+        assert!(error.contains("zero-sized"), "{}", error);
+    }
+
+    #[test]
+    fn bounds_type_nesting_when_parsing() {
+        assert!(ParamType::parse(&format!("uint8{}", "[]".repeat(32)), None).is_ok());
+        let error = ParamType::parse(&format!("uint8{}", "[]".repeat(100_000)), None).unwrap_err();
+        assert!(error.contains("nesting"), "{}", error);
+        let parens = format!("{}uint8{}", "(".repeat(100_000), ")".repeat(100_000));
+        assert!(ParamType::parse(&parens, None).is_err());
+
+        // 每层 tuple[] 占数组和元组两层，16 层恰好达到上限
+        let nested = |levels: usize| {
+            let mut param = json!({"name": "x", "type": "uint8"});
+            for _ in 0..levels {
+                param = json!({"name": "x", "type": "tuple[]", "components": [param]});
+            }
+            json!({"name": "f", "inputs": [param]}).to_string()
+        };
+        assert!(Fragment::from_json(&nested(16)).is_ok());
+        let error = Fragment::from_json(&nested(17)).unwrap_err();
+        assert!(error.contains("nesting"), "{}", error);
+    }
+}
@@ -0,0 +1,205 @@
+//! Bech32 / Bech32m 编码（BIP173、BIP350）
+
+/// Bech32 字符表
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// 整个编码字符串的最大长度
+const MAX_LENGTH: usize = 90;
+
+/// 校验和变体：见证版本 0 使用 Bech32，版本 1 及以上使用 Bech32m
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variant {
+    Bech32,
+    Bech32m,
+}
+
+impl Variant {
+    fn constant(self) -> u32 {
+        match self {
+            Variant::Bech32 => 1,
+            Variant::Bech32m => 0x2bc8_30a3,
+        }
+    }
+}
+
+/// 将 5 位分组的数据按指定变体编码为 `hrp1...` 字符串
+pub fn encode(hrp: &str, data: &[u8], variant: Variant) -> Result<String, String> {
+    if hrp.is_empty() || !hrp.bytes().all(|b| (33..=126).contains(&b)) {
+        return Err(format!("Invalid bech32 human-readable part: '{}'", hrp));
+    }
+    if data.iter().any(|&value| value > 31) {
+        return Err("Bech32 data must be 5-bit groups".to_string());
+    }
+
+    let hrp = hrp.to_lowercase();
+    let mut values = expand_hrp(&hrp);
+    values.extend_from_slice(data);
+    values.extend_from_slice(&[0u8; 6]);
+    let checksum = polymod(&values) ^ variant.constant();
+
+    let mut encoded = hrp;
+    encoded.push('1');
+    encoded.extend(data.iter().map(|&value| CHARSET[value as usize] as char));
+    encoded.extend((0..6).map(|i| CHARSET[((checksum >> (5 * (5 - i))) & 31) as usize] as char));
+
+    if encoded.len() > MAX_LENGTH {
+        return Err(format!("Bech32 string exceeds {} characters", MAX_LENGTH));
+    }
+    Ok(encoded)
+}
+
+/// 编码隔离见证地址：版本 0 使用 Bech32，版本 1–16 使用 Bech32m
+pub fn encode_segwit(hrp: &str, version: u8, program: &[u8]) -> Result<String, String> {
+    if version > 16 {
+        return Err(format!("Invalid witness version: {}", version));
+    }
+    if !(2..=40).contains(&program.len()) || (version == 0 && ![20, 32].contains(&program.len())) {
+        return Err(format!(
+            "Invalid witness program length for version {}: {}",
+            version,
+            program.len()
+        ));
+    }
+
+    let mut data = vec![version];
+    data.extend(convert_bits(program, 8, 5, true)?);
+    let variant = if version == 0 {
+        Variant::Bech32
+    } else {
+        Variant::Bech32m
+    };
+    encode(hrp, &data, variant)
+}
+
+/// 解码 `hrp1...` 字符串，返回 (小写 hrp, 5 位分组数据, 校验和变体)
+pub fn decode(value: &str) -> Result<(String, Vec<u8>, Variant), String> {
+    if value.len() > MAX_LENGTH {
+        return Err(format!("Bech32 string exceeds {} characters", MAX_LENGTH));
+    }
+    if value.chars().any(|c| c.is_ascii_lowercase())
+        && value.chars().any(|c| c.is_ascii_uppercase())
+    {
+        return Err("Bech32 string must not mix upper and lower case".to_string());
+    }
+
+    let value = value.to_lowercase();
+    let separator = value
+        .rfind('1')
+        .ok_or_else(|| "Bech32 string has no separator".to_string())?;
+    let (hrp, rest) = (&value[..separator], &value[separator + 1..]);
+    if hrp.is_empty() || rest.len() < 6 || !hrp.bytes().all(|b| (33..=126).contains(&b)) {
+        return Err(format!("Invalid bech32 string: '{}'", value));
+    }
+
+    let data = rest
+        .bytes()
+        .map(|b| {
+            CHARSET
+                .iter()
+                .position(|&c| c == b)
+                .map(|i| i as u8)
+                .ok_or_else(|| format!("Invalid bech32 character: '{}'", b as char))
+        })
+        .collect::<Result<Vec<u8>, String>>()?;
+
+    let mut values = expand_hrp(hrp);
+    values.extend_from_slice(&data);
+    let variant = match polymod(&values) {
+        c if c == Variant::Bech32.constant() => Variant::Bech32,
+        c if c == Variant::Bech32m.constant() => Variant::Bech32m,
+        _ => return Err("Invalid bech32 checksum".to_string()),
+    };
+
+    let payload_len = data.len() - 6;
+    Ok((hrp.to_string(), data[..payload_len].to_vec(), variant))
+}
+
+/// 解码隔离见证地址，校验 hrp 和版本对应的校验和变体，返回 (见证版本, 见证程序)
+pub fn decode_segwit(hrp: &str, address: &str) -> Result<(u8, Vec<u8>), String> {
+    let (decoded_hrp, data, variant) = decode(address)?;
+    if decoded_hrp != hrp {
+        return Err(format!(
+            "Address prefix mismatch: expected '{}', got '{}'",
+            hrp, decoded_hrp
+        ));
+    }
+
+    let (&version, program) = data
+        .split_first()
+        .ok_or_else(|| "Empty witness program".to_string())?;
+    let program = convert_bits(program, 5, 8, false)?;
+    let expected = if version == 0 {
+        Variant::Bech32
+    } else {
+        Variant::Bech32m
+    };
+    if variant != expected {
+        return Err(format!(
+            "Wrong checksum variant for witness version {}",
+            version
+        ));
+    }
+
+    // 按编码规则再校验一次版本和程序长度
+    encode_segwit(hrp, version, &program)?;
+    Ok((version, program))
+}
+
+/// 在不同位宽的分组之间转换，例如 8 位字节转为 5 位分组
+pub fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, String> {
+    let max_value = (1u32 << to) - 1;
+    let mut accumulator = 0u32;
+    let mut bits = 0u32;
+    let mut output = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+
+    for &value in data {
+        let value = u32::from(value);
+        if value >> from != 0 {
+            return Err(format!("Invalid {}-bit value: {}", from, value));
+        }
+        accumulator = (accumulator << from) | value;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            output.push(((accumulator >> bits) & max_value) as u8);
+        }
+    }
+
+    if pad {
+        if bits > 0 {
+            output.push(((accumulator << (to - bits)) & max_value) as u8);
+        }
+    } else if bits >= from || (accumulator << (to - bits)) & max_value != 0 {
+        return Err("Invalid padding in bit conversion".to_string());
+    }
+    Ok(output)
+}
+
+fn expand_hrp(hrp: &str) -> Vec<u8> {
+    let bytes = hrp.as_bytes();
+    let mut expanded = Vec::with_capacity(bytes.len() * 2 + 1);
+    expanded.extend(bytes.iter().map(|b| b >> 5));
+    expanded.push(0);
+    expanded.extend(bytes.iter().map(|b| b & 31));
+    expanded
+}
+
+fn polymod(values: &[u8]) -> u32 {
+    const GENERATORS: [u32; 5] = [
+        0x3b6a_57b2,
+        0x2650_8e6d,
+        0x1ea1_19fa,
+        0x3d42_33dd,
+        0x2a14_62b3,
+    ];
+
+    values.iter().fold(1u32, |checksum, &value| {
+        let top = checksum >> 25;
+        let checksum = ((checksum & 0x01ff_ffff) << 5) ^ u32::from(value);
+        GENERATORS
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(checksum, |checksum, (_, generator)| checksum ^ generator)
+    })
+}
@@ -0,0 +1,237 @@
+//! BIP32 分层确定性密钥派生
+//!
+//! 支持从 BIP39 种子生成主密钥、硬化/普通子密钥派生，
+//! 以及解析 `m/44'/60'/0'/0/5` 形式的 BIP44 路径。
+
+use std::fmt;
+use std::str::FromStr;
+
+use hmac::{Hmac, Mac};
+use secp256k1::{PublicKey, Scalar, Secp256k1, SecretKey, Signing};
+use sha2::Sha512;
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// 硬化派生索引的起始值（2^31）
+pub const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// BIP32 主密钥的 HMAC 密钥
+const MASTER_HMAC_KEY: &[u8] = b"Bitcoin seed";
+
+/// 派生路径中的一级索引
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChildNumber {
+    Normal(u32),
+    Hardened(u32),
+}
+
+impl ChildNumber {
+    /// 返回写入 HMAC 数据的32位索引（硬化索引已加上 2^31）
+    pub fn to_index(self) -> u32 {
+        match self {
+            ChildNumber::Normal(index) => index,
+            ChildNumber::Hardened(index) => index | HARDENED_OFFSET,
+        }
+    }
+
+    pub fn is_hardened(self) -> bool {
+        matches!(self, ChildNumber::Hardened(_))
+    }
+}
+
+impl FromStr for ChildNumber {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (digits, hardened) = match s.strip_suffix(['\'', 'h', 'H']) {
+            Some(digits) => (digits, true),
+            None => (s, false),
+        };
+
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("Invalid path component: '{}'", s));
+        }
+
+        let index: u32 = digits
+            .parse()
+            .map_err(|_| format!("Path index out of range: '{}'", s))?;
+        if index >= HARDENED_OFFSET {
+            return Err(format!("Path index out of range: '{}'", s));
+        }
+
+        Ok(if hardened {
+            ChildNumber::Hardened(index)
+        } else {
+            ChildNumber::Normal(index)
+        })
+    }
+}
+
+impl fmt::Display for ChildNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChildNumber::Normal(index) => write!(f, "{}", index),
+            ChildNumber::Hardened(index) => write!(f, "{}'", index),
+        }
+    }
+}
+
+/// BIP32 派生路径，例如 `m/44'/60'/0'/0/5`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DerivationPath(Vec<ChildNumber>);
+
+impl DerivationPath {
+    pub fn children(&self) -> &[ChildNumber] {
+        &self.0
+    }
+}
+
+impl From<Vec<ChildNumber>> for DerivationPath {
+    fn from(children: Vec<ChildNumber>) -> Self {
+        DerivationPath(children)
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().split('/');
+        match parts.next() {
+            Some("m") | Some("M") => {}
+            _ => return Err(format!("Derivation path must start with 'm': '{}'", s)),
+        }
+
+        parts
+            .filter(|part| !part.is_empty())
+            .map(ChildNumber::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .map(DerivationPath)
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "m")?;
+        for child in &self.0 {
+            write!(f, "/{}", child)?;
+        }
+        Ok(())
+    }
+}
+
+/// BIP32 扩展私钥
+#[derive(Clone)]
+pub struct ExtendedPrivateKey {
+    secret_key: SecretKey,
+    chain_code: [u8; 32],
+}
+
+impl ExtendedPrivateKey {
+    /// 从种子生成主密钥：I = HMAC-SHA512("Bitcoin seed", seed)
+    pub fn master(seed: &[u8]) -> Result<Self, String> {
+        if !(16..=64).contains(&seed.len()) {
+            return Err(format!(
+                "Seed must be between 16 and 64 bytes, got {}",
+                seed.len()
+            ));
+        }
+
+        let (il, ir) = hmac_sha512(MASTER_HMAC_KEY, &[seed]);
+        let secret_key = SecretKey::from_slice(&il)
+            .map_err(|e| format!("Invalid master key derived from seed: {}", e))?;
+
+        Ok(ExtendedPrivateKey {
+            secret_key,
+            chain_code: ir,
+        })
+    }
+
+    /// 派生一级子密钥（CKDpriv）
+    pub fn derive_child<C: Signing>(
+        &self,
+        secp: &Secp256k1<C>,
+        child: ChildNumber,
+    ) -> Result<Self, String> {
+        let index = child.to_index().to_be_bytes();
+        let (il, ir) = if child.is_hardened() {
+            let secret = self.secret_key.secret_bytes();
+            hmac_sha512(&self.chain_code, &[&[0u8], &secret, &index])
+        } else {
+            let public = PublicKey::from_secret_key(secp, &self.secret_key).serialize();
+            hmac_sha512(&self.chain_code, &[&public, &index])
+        };
+
+        let tweak = Scalar::from_be_bytes(il)
+            .map_err(|_| format!("Invalid child key at index {}", child))?;
+        let secret_key = self
+            .secret_key
+            .add_tweak(&tweak)
+            .map_err(|_| format!("Invalid child key at index {}", child))?;
+
+        Ok(ExtendedPrivateKey {
+            secret_key,
+            chain_code: ir,
+        })
+    }
+
+    /// 沿路径逐级派生
+    pub fn derive_path<C: Signing>(
+        &self,
+        secp: &Secp256k1<C>,
+        path: &DerivationPath,
+    ) -> Result<Self, String> {
+        path.children()
+            .iter()
+            .try_fold(self.clone(), |key, child| key.derive_child(secp, *child))
+    }
+
+    pub fn secret_bytes(&self) -> [u8; 32] {
+        self.secret_key.secret_bytes()
+    }
+}
+
+pub fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> ([u8; 32], [u8; 32]) {
+    let mut mac = HmacSha512::new_from_slice(key).expect("HMAC accepts keys of any length");
+    for chunk in data {
+        mac.update(chunk);
+    }
+    let output = mac.finalize().into_bytes();
+
+    let mut il = [0u8; 32];
+    let mut ir = [0u8; 32];
+    il.copy_from_slice(&output[..32]);
+    ir.copy_from_slice(&output[32..]);
+    (il, ir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mnemonic::mnemonic_to_seed;
+
+    #[test]
+    fn derives_hardhat_account() {
+        // Hardhat 默认账户 0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266
+        let seed = mnemonic_to_seed(
+            "test test test test test test test test test test test junk",
+            "",
+        );
+        let path: DerivationPath = "m/44'/60'/0'/0/0".parse().unwrap();
+        let key = ExtendedPrivateKey::master(&seed)
+            .unwrap()
+            .derive_path(&Secp256k1::new(), &path)
+            .unwrap();
+        assert_eq!(
+            hex::encode(key.secret_bytes()),
+            "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
+        );
+    }
+
+    #[test]
+    fn parses_paths() {
+        let path: DerivationPath = "m/44'/60'/0'/0/5".parse().unwrap();
+        assert_eq!(path.to_string(), "m/44'/60'/0'/0/5");
+        assert!("m/2147483648".parse::<DerivationPath>().is_err());
+    }
+}
@@ -0,0 +1,432 @@
+//! 比特币地址
+//!
+//! 按 BIP44/49/84/86 分别生成 P2PKH、P2SH-P2WPKH、P2WPKH、P2TR 地址，
+//! 支持主网、测试网和回归测试网。链类型形如 `bitcoin`、`bitcoin-testnet:p2tr`，
+//! 冒号后为地址类型，省略时默认为 P2WPKH（BIP84）。
+
+use bitcoin_hashes::{hash160, sha256d, Hash};
+use secp256k1::{PublicKey, Scalar, Secp256k1, SecretKey, Verification};
+use sha2::{Digest, Sha256};
+
+use crate::bech32;
+use crate::chain::{self, ChainAccount, ChainAdapter};
+use crate::error::WalletError;
+use crate::secret::Secret;
+
+/// BIP86 密钥调整使用的标签哈希名称
+const TAP_TWEAK_TAG: &[u8] = b"TapTweak";
+
+/// Base58Check 校验和长度
+const CHECKSUM_LEN: usize = 4;
+
+/// BIP137 消息签名的前缀
+const MESSAGE_MAGIC: &[u8] = b"Bitcoin Signed Message:\n";
+
+/// 比特币网络
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Network {
+    Mainnet,
+    Testnet,
+    Regtest,
+}
+
+impl Network {
+    /// SLIP-44 币种编号：主网为 0，测试网和回归测试网为 1
+    pub fn coin_type(self) -> u32 {
+        match self {
+            Network::Mainnet => 0,
+            Network::Testnet | Network::Regtest => 1,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Network::Mainnet => "mainnet",
+            Network::Testnet => "testnet",
+            Network::Regtest => "regtest",
+        }
+    }
+
+    fn p2pkh_version(self) -> u8 {
+        match self {
+            Network::Mainnet => 0x00,
+            Network::Testnet | Network::Regtest => 0x6f,
+        }
+    }
+
+    fn p2sh_version(self) -> u8 {
+        match self {
+            Network::Mainnet => 0x05,
+            Network::Testnet | Network::Regtest => 0xc4,
+        }
+    }
+
+    fn wif_version(self) -> u8 {
+        match self {
+            Network::Mainnet => 0x80,
+            Network::Testnet | Network::Regtest => 0xef,
+        }
+    }
+
+    fn bech32_hrp(self) -> &'static str {
+        match self {
+            Network::Mainnet => "bc",
+            Network::Testnet => "tb",
+            Network::Regtest => "bcrt",
+        }
+    }
+}
+
+/// 比特币地址类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressType {
+    /// 传统地址（BIP44）
+    P2pkh,
+    /// 嵌套隔离见证（BIP49）
+    P2shP2wpkh,
+    /// 原生隔离见证（BIP84）
+    P2wpkh,
+    /// Taproot 单密钥（BIP86）
+    P2tr,
+}
+
+impl AddressType {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.trim().to_lowercase().as_str() {
+            "" | "p2wpkh" | "segwit" | "native-segwit" | "bip84" => Ok(AddressType::P2wpkh),
+            "p2pkh" | "legacy" | "bip44" => Ok(AddressType::P2pkh),
+            "p2sh-p2wpkh" | "p2wpkh-p2sh" | "nested-segwit" | "bip49" => {
+                Ok(AddressType::P2shP2wpkh)
+            }
+            "p2tr" | "taproot" | "bip86" => Ok(AddressType::P2tr),
+            _ => Err(format!("Unsupported Bitcoin address type: '{}'", name)),
+        }
+    }
+
+    /// 派生路径的 purpose 字段
+    pub fn purpose(self) -> u32 {
+        match self {
+            AddressType::P2pkh => 44,
+            AddressType::P2shP2wpkh => 49,
+            AddressType::P2wpkh => 84,
+            AddressType::P2tr => 86,
+        }
+    }
+}
+
+/// 解析比特币链类型，返回 (网络, 地址类型)；非比特币链返回 Ok(None)
+pub fn parse_chain(chain_type: &str) -> Result<Option<(Network, AddressType)>, String> {
+    let chain_type = chain_type.to_lowercase();
+    let (chain, address_type) = match chain_type.split_once(':') {
+        Some((chain, address_type)) => (chain, address_type),
+        None => (chain_type.as_str(), ""),
+    };
+
+    let network = match chain {
+        "bitcoin" | "btc" => Network::Mainnet,
+        "bitcoin-testnet" | "btc-testnet" | "tbtc" => Network::Testnet,
+        "bitcoin-regtest" | "btc-regtest" => Network::Regtest,
+        _ => return Ok(None),
+    };
+    Ok(Some((network, AddressType::parse(address_type)?)))
+}
+
+/// 由压缩公钥生成指定类型的地址
+pub fn encode_address<C: Verification>(
+    secp: &Secp256k1<C>,
+    public_key: &PublicKey,
+    network: Network,
+    address_type: AddressType,
+) -> Result<String, String> {
+    let key_hash = hash160::Hash::hash(&public_key.serialize()).to_byte_array();
+    match address_type {
+        AddressType::P2pkh => Ok(base58check_encode(network.p2pkh_version(), &key_hash)),
+        AddressType::P2shP2wpkh => {
+            // redeemScript = OP_0 <20字节公钥哈希>
+            let mut redeem_script = vec![0x00, 0x14];
+            redeem_script.extend_from_slice(&key_hash);
+            let script_hash = hash160::Hash::hash(&redeem_script).to_byte_array();
+            Ok(base58check_encode(network.p2sh_version(), &script_hash))
+        }
+        AddressType::P2wpkh => bech32::encode_segwit(network.bech32_hrp(), 0, &key_hash),
+        AddressType::P2tr => {
+            let output_key = taproot_output_key(secp, public_key)?;
+            bech32::encode_segwit(network.bech32_hrp(), 1, &output_key)
+        }
+    }
+}
+
+/// 以压缩公钥格式导出 WIF 私钥
+pub fn encode_wif(secret_key: &SecretKey, network: Network) -> String {
+    let mut payload = secret_key.secret_bytes().to_vec();
+    payload.push(0x01);
+    base58check_encode(network.wif_version(), &payload)
+}
+
+/// 解析 WIF 私钥，校验网络版本
+pub fn decode_wif(wif: &str, network: Network) -> Result<SecretKey, String> {
+    let (version, payload) = base58check_decode(wif.trim())?;
+    if version != network.wif_version() {
+        return Err(format!("WIF key is not for {}", network.name()));
+    }
+    let secret = match payload.len() {
+        33 if payload[32] == 0x01 => &payload[..32],
+        32 => &payload[..],
+        len => return Err(format!("Invalid WIF payload length: {}", len)),
+    };
+    SecretKey::from_slice(secret).map_err(|e| format!("Invalid secp256k1 private key: {}", e))
+}
+
+/// 校验地址是否属于该网络（任意地址类型）
+pub fn validate_address(address: &str, network: Network) -> Result<(), String> {
+    let address = address.trim();
+    if address
+        .to_lowercase()
+        .starts_with(&format!("{}1", network.bech32_hrp()))
+    {
+        return bech32::decode_segwit(network.bech32_hrp(), address).map(|_| ());
+    }
+
+    let (version, payload) = base58check_decode(address)?;
+    if version != network.p2pkh_version() && version != network.p2sh_version() {
+        return Err(format!(
+            "Address version 0x{:02x} is not valid on {}",
+            version,
+            network.name()
+        ));
+    }
+    if payload.len() != 20 {
+        return Err(format!("Invalid address payload length: {}", payload.len()));
+    }
+    Ok(())
+}
+
+/// BIP137 消息摘要：sha256d(varstr(magic) || varstr(message))
+fn message_digest(message: &[u8]) -> [u8; 32] {
+    let mut data = Vec::with_capacity(MESSAGE_MAGIC.len() + message.len() + 10);
+    write_varint(&mut data, MESSAGE_MAGIC.len() as u64);
+    data.extend_from_slice(MESSAGE_MAGIC);
+    write_varint(&mut data, message.len() as u64);
+    data.extend_from_slice(message);
+    sha256d::Hash::hash(&data).to_byte_array()
+}
+
+fn write_varint(data: &mut Vec<u8>, value: u64) {
+    match value {
+        0..=0xfc => data.push(value as u8),
+        0xfd..=0xffff => {
+            data.push(0xfd);
+            data.extend_from_slice(&(value as u16).to_le_bytes());
+        }
+        0x1_0000..=0xffff_ffff => {
+            data.push(0xfe);
+            data.extend_from_slice(&(value as u32).to_le_bytes());
+        }
+        _ => {
+            data.push(0xff);
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+}
+
+/// BIP86 输出密钥：Q = P + int(hash_TapTweak(P))·G，其中 P 为 x-only 内部公钥
+fn taproot_output_key<C: Verification>(
+    secp: &Secp256k1<C>,
+    public_key: &PublicKey,
+) -> Result<[u8; 32], String> {
+    let (internal_key, _) = public_key.x_only_public_key();
+    let tweak = Scalar::from_be_bytes(tagged_hash(TAP_TWEAK_TAG, &internal_key.serialize()))
+        .map_err(|_| "Taproot tweak out of range".to_string())?;
+    let (output_key, _) = internal_key
+        .add_tweak(secp, &tweak)
+        .map_err(|e| format!("Failed to tweak Taproot key: {}", e))?;
+    Ok(output_key.serialize())
+}
+
+/// BIP340 标签哈希：SHA256(SHA256(tag) || SHA256(tag) || msg)
+fn tagged_hash(tag: &[u8], msg: &[u8]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag);
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    hasher.update(msg);
+    hasher.finalize().into()
+}
+
+/// Base58Check 编码：base58(version || payload || sha256d 前4字节)
+pub fn base58check_encode(version: u8, payload: &[u8]) -> String {
+    let mut data = Vec::with_capacity(1 + payload.len() + CHECKSUM_LEN);
+    data.push(version);
+    data.extend_from_slice(payload);
+    let checksum = sha256d::Hash::hash(&data).to_byte_array();
+    data.extend_from_slice(&checksum[..CHECKSUM_LEN]);
+    bs58::encode(data).into_string()
+}
+
+/// Base58Check 解码，返回 (version, payload)
+pub fn base58check_decode(value: &str) -> Result<(u8, Vec<u8>), String> {
+    let data = bs58::decode(value)
+        .into_vec()
+        .map_err(|e| format!("Invalid base58 string: {}", e))?;
+    if data.len() <= CHECKSUM_LEN {
+        return Err("Base58Check data too short".to_string());
+    }
+
+    let (body, checksum) = data.split_at(data.len() - CHECKSUM_LEN);
+    let expected = sha256d::Hash::hash(body).to_byte_array();
+    if checksum != &expected[..CHECKSUM_LEN] {
+        return Err("Invalid Base58Check checksum".to_string());
+    }
+    Ok((body[0], body[1..].to_vec()))
+}
+
+/// 比特币适配器：私钥为 WIF，公钥为压缩格式十六进制，签名为 BIP137 格式（header || r || s）
+pub struct BitcoinAdapter {
+    network: Network,
+    address_type: AddressType,
+}
+
+pub fn adapter(chain_type: &str) -> Result<Option<Box<dyn ChainAdapter>>, WalletError> {
+    let parsed = parse_chain(chain_type).map_err(|_| WalletError::UnsupportedChain {
+        chain_type: chain_type.to_string(),
+    })?;
+    Ok(parsed.map(|(network, address_type)| {
+        Box::new(BitcoinAdapter {
+            network,
+            address_type,
+        }) as Box<dyn ChainAdapter>
+    }))
+}
+
+impl ChainAdapter for BitcoinAdapter {
+    fn name(&self) -> &'static str {
+        "bitcoin"
+    }
+
+    /// `m/purpose'/coin_type'/0'/0/index`
+    fn account_path(&self, index: u32) -> String {
+        format!(
+            "m/{}'/{}'/0'/0/{}",
+            self.address_type.purpose(),
+            self.network.coin_type(),
+            index
+        )
+    }
+
+    fn derive_account(&self, seed: &[u8], path: &str) -> Result<ChainAccount, WalletError> {
+        let secret_key = chain::derive_secp256k1(seed, path)?;
+        let public =
+            PublicKey::from_secret_key(&Secp256k1::signing_only(), &secret_key).serialize();
+        Ok(ChainAccount {
+            public_key: hex::encode(public),
+            private_key: Secret::new(encode_wif(&secret_key, self.network)),
+            address: self.format_address(&public)?,
+        })
+    }
+
+    fn format_address(&self, public_key: &[u8]) -> Result<String, WalletError> {
+        let public = PublicKey::from_slice(public_key).map_err(|e| {
+            WalletError::invalid_key(format!("Invalid secp256k1 public key: {}", e))
+        })?;
+        encode_address(
+            &Secp256k1::verification_only(),
+            &public,
+            self.network,
+            self.address_type,
+        )
+        .map_err(WalletError::derivation)
+    }
+
+    fn validate_address(&self, address: &str) -> Result<(), WalletError> {
+        validate_address(address, self.network).map_err(WalletError::invalid_address)
+    }
+
+    /// BIP137：header = 27 + recovery_id + 地址类型偏移（P2PKH 4、P2SH-P2WPKH 8、P2WPKH 12）
+    fn sign(&self, private_key: &str, message: &[u8]) -> Result<Vec<u8>, WalletError> {
+        let header_offset = match self.address_type {
+            AddressType::P2pkh => 4,
+            AddressType::P2shP2wpkh => 8,
+            AddressType::P2wpkh => 12,
+            AddressType::P2tr => {
+                return Err(WalletError::unsupported(
+                    "Message signing is not supported for P2TR addresses",
+                ))
+            }
+        };
+
+        let secret_key = decode_wif(private_key, self.network).map_err(WalletError::invalid_key)?;
+        let recoverable = chain::sign_recoverable(&secret_key, message_digest(message))?;
+
+        let mut signature = Vec::with_capacity(65);
+        signature.push(27 + header_offset + recoverable[64]);
+        signature.extend_from_slice(&recoverable[..64]);
+        Ok(signature)
+    }
+
+    fn verify(
+        &self,
+        public_key: &str,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, WalletError> {
+        if signature.len() != 65 {
+            return Err(WalletError::invalid_signature(format!(
+                "BIP137 signature must be 65 bytes, got {}",
+                signature.len()
+            )));
+        }
+        let public = chain::parse_secp256k1_public(public_key)?;
+        chain::verify_ecdsa(&public, message_digest(message), &signature[1..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn first_address(chain_type: &str) -> String {
+        let adapter = adapter(chain_type).unwrap().unwrap();
+        let seed = crate::mnemonic::mnemonic_to_seed(PHRASE, "");
+        adapter
+            .derive_account(&seed, &adapter.default_path())
+            .unwrap()
+            .address
+    }
+
+    #[test]
+    fn derives_bip_vectors() {
+        // BIP44/49/84/86 各自文档中的首个接收地址
+        assert_eq!(
+            first_address("bitcoin:p2pkh"),
+            "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"
+        );
+        assert_eq!(
+            first_address("bitcoin:p2sh-p2wpkh"),
+            "37VucYSaXLCAsxYyAPfbSi9eh4iEcbShgf"
+        );
+        assert_eq!(
+            first_address("bitcoin-testnet:p2sh-p2wpkh"),
+            "2Mww8dCYPUpKHofjgcXcBCEGmniw9CoaiD2"
+        );
+        assert_eq!(
+            first_address("bitcoin"),
+            "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+        );
+        assert_eq!(
+            first_address("bitcoin:taproot"),
+            "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr"
+        );
+    }
+
+    #[test]
+    fn round_trips_wif() {
+        let mut secret = [0u8; 32];
+        secret[31] = 1;
+        let secret_key = SecretKey::from_slice(&secret).unwrap();
+        let wif = "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn";
+        assert_eq!(encode_wif(&secret_key, Network::Mainnet), wif);
+        assert_eq!(decode_wif(wif, Network::Mainnet).unwrap(), secret_key);
+    }
+}
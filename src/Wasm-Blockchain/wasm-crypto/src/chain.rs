@@ -0,0 +1,200 @@
+//! 链适配器
+//!
+//! 每条链实现一次 `ChainAdapter`（派生密钥、生成/校验地址、签名、验签），
+//! 并在 `REGISTRY` 中登记；按链类型查找适配器，未知链类型返回错误。
+
+use std::str::FromStr;
+
+use secp256k1::ecdsa::{RecoverableSignature, RecoveryId, Signature};
+use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+
+use crate::bip32::{DerivationPath, ExtendedPrivateKey};
+use crate::error::WalletError;
+use crate::secret::Secret;
+use crate::{bitcoin, cosmos, ethereum, mnemonic, solana, substrate, tron};
+
+/// 派生出的账户，字符串格式由各链决定
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainAccount {
+    pub public_key: String,
+    pub private_key: Secret<String>,
+    pub address: String,
+}
+
+pub trait ChainAdapter {
+    /// 链名称，用于日志
+    fn name(&self) -> &'static str;
+
+    /// 第 `index` 个账户的派生路径
+    fn account_path(&self, index: u32) -> String;
+
+    /// 钱包默认使用的派生路径
+    fn default_path(&self) -> String {
+        self.account_path(0)
+    }
+
+    /// 由助记词计算派生用的种子，默认为 BIP39 种子
+    fn seed(&self, mnemonic_words: &str, passphrase: &str) -> Result<Secret<Vec<u8>>, WalletError> {
+        Ok(Secret::new(
+            mnemonic::mnemonic_to_seed(mnemonic_words, passphrase).to_vec(),
+        ))
+    }
+
+    /// 从种子沿路径派生账户
+    fn derive_account(&self, seed: &[u8], path: &str) -> Result<ChainAccount, WalletError>;
+
+    /// 由公钥生成地址
+    fn format_address(&self, public_key: &[u8]) -> Result<String, WalletError>;
+
+    /// 校验地址格式和校验和
+    fn validate_address(&self, address: &str) -> Result<(), WalletError>;
+
+    /// 用该链导出格式的私钥对消息签名
+    fn sign(&self, private_key: &str, message: &[u8]) -> Result<Vec<u8>, WalletError>;
+
+    /// 用该链导出格式的公钥验证签名
+    fn verify(
+        &self,
+        public_key: &str,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, WalletError>;
+}
+
+type AdapterFactory = fn(&str) -> Result<Option<Box<dyn ChainAdapter>>, WalletError>;
+
+/// 已注册的适配器，按顺序匹配链类型
+const REGISTRY: &[AdapterFactory] = &[
+    ethereum::adapter,
+    substrate::adapter,
+    bitcoin::adapter,
+    solana::adapter,
+    cosmos::adapter,
+    tron::adapter,
+];
+
+/// 按链类型查找适配器
+pub fn adapter_for(chain_type: &str) -> Result<Box<dyn ChainAdapter>, WalletError> {
+    let chain_type = chain_type.trim();
+    for factory in REGISTRY {
+        if let Some(adapter) = factory(chain_type)? {
+            return Ok(adapter);
+        }
+    }
+    Err(WalletError::UnsupportedChain {
+        chain_type: chain_type.to_string(),
+    })
+}
+
+/// 从 BIP39 种子沿 BIP32 路径派生 secp256k1 私钥
+pub fn derive_secp256k1(seed: &[u8], path: &str) -> Result<SecretKey, WalletError> {
+    let parsed = DerivationPath::from_str(path).map_err(|e| WalletError::invalid_path(path, e))?;
+    let secp = Secp256k1::signing_only();
+    let secret = ExtendedPrivateKey::master(seed)
+        .and_then(|master| master.derive_path(&secp, &parsed))
+        .map_err(WalletError::derivation)?
+        .secret_bytes();
+    SecretKey::from_slice(&secret)
+        .map_err(|e| WalletError::derivation(format!("Invalid secp256k1 private key: {}", e)))
+}
+
+/// 解析十六进制 secp256k1 私钥，允许带0x前缀
+pub fn parse_secp256k1_secret(private_key: &str) -> Result<SecretKey, WalletError> {
+    let bytes = decode_hex(private_key)
+        .map_err(|e| WalletError::invalid_key(format!("Invalid hex private key: {}", e)))?;
+    SecretKey::from_slice(&bytes)
+        .map_err(|e| WalletError::invalid_key(format!("Invalid secp256k1 private key: {}", e)))
+}
+
+/// 解析十六进制 secp256k1 公钥（压缩或未压缩），允许带0x前缀
+pub fn parse_secp256k1_public(public_key: &str) -> Result<PublicKey, WalletError> {
+    let bytes = decode_hex(public_key)
+        .map_err(|e| WalletError::invalid_key(format!("Invalid hex public key: {}", e)))?;
+    PublicKey::from_slice(&bytes)
+        .map_err(|e| WalletError::invalid_key(format!("Invalid secp256k1 public key: {}", e)))
+}
+
+/// 对32字节摘要做可恢复 ECDSA 签名，返回 r || s || recovery_id
+pub fn sign_recoverable(secret_key: &SecretKey, digest: [u8; 32]) -> Result<[u8; 65], WalletError> {
+    let message = Message::from_slice(&digest).map_err(|e| WalletError::crypto(e.to_string()))?;
+    let (recovery_id, compact) = Secp256k1::signing_only()
+        .sign_ecdsa_recoverable(&message, secret_key)
+        .serialize_compact();
+
+    let mut signature = [0u8; 65];
+    signature[..64].copy_from_slice(&compact);
+    signature[64] = recovery_id.to_i32() as u8;
+    Ok(signature)
+}
+
+/// 用公钥验证32字节摘要上的 ECDSA 签名，只使用签名的前64字节 (r || s)
+pub fn verify_ecdsa(
+    public_key: &PublicKey,
+    digest: [u8; 32],
+    signature: &[u8],
+) -> Result<bool, WalletError> {
+    if signature.len() != 64 && signature.len() != 65 {
+        return Err(WalletError::invalid_signature(format!(
+            "ECDSA signature must be 64 or 65 bytes, got {}",
+            signature.len()
+        )));
+    }
+
+    let message = Message::from_slice(&digest).map_err(|e| WalletError::crypto(e.to_string()))?;
+    let mut signature = Signature::from_compact(&signature[..64])
+        .map_err(|e| WalletError::invalid_signature(format!("Invalid ECDSA signature: {}", e)))?;
+    signature.normalize_s();
+    Ok(Secp256k1::verification_only()
+        .verify_ecdsa(&message, &signature, public_key)
+        .is_ok())
+}
+
+/// 由32字节摘要和65字节签名 r || s || v 恢复 secp256k1 公钥，v 可为 0/1 或 27/28
+pub fn recover_secp256k1(digest: [u8; 32], signature: &[u8]) -> Result<PublicKey, WalletError> {
+    if signature.len() != 65 {
+        return Err(WalletError::invalid_signature(format!(
+            "Recoverable signature must be 65 bytes, got {}",
+            signature.len()
+        )));
+    }
+    let recovery_id = match signature[64] {
+        v @ 0..=1 => v,
+        v @ 27..=28 => v - 27,
+        v => {
+            return Err(WalletError::invalid_signature(format!(
+                "Invalid recovery id: {}",
+                v
+            )));
+        }
+    };
+
+    let message = Message::from_slice(&digest).map_err(|e| WalletError::crypto(e.to_string()))?;
+    let recovery_id = RecoveryId::from_i32(i32::from(recovery_id))
+        .map_err(|e| WalletError::invalid_signature(e.to_string()))?;
+    let signature = RecoverableSignature::from_compact(&signature[..64], recovery_id)
+        .map_err(|e| WalletError::invalid_signature(format!("Invalid ECDSA signature: {}", e)))?;
+    Secp256k1::verification_only()
+        .recover_ecdsa(&message, &signature)
+        .map_err(|e| WalletError::invalid_signature(format!("Failed to recover public key: {}", e)))
+}
+
+/// 解码十六进制字符串，允许带0x前缀
+pub fn decode_hex(value: &str) -> Result<Vec<u8>, String> {
+    let value = value.trim();
+    let value = value.strip_prefix("0x").unwrap_or(value);
+    hex::decode(value).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn looks_up_registered_adapters() {
+        assert_eq!(adapter_for(" ethereum ").unwrap().name(), "ethereum");
+        assert_eq!(
+            adapter_for("dogecoin").err().unwrap().code(),
+            "UNSUPPORTED_CHAIN"
+        );
+    }
+}
@@ -0,0 +1,163 @@
+//! Cosmos SDK 账户
+//!
+//! secp256k1 密钥沿 `m/44'/coin_type'/0'/0/n` 派生（默认币种 118），
+//! 地址为 bech32(hrp, ripemd160(sha256(压缩公钥)))，前缀由调用方指定，
+//! 因此同一接口可覆盖整个 IBC 生态。
+
+use bitcoin_hashes::{hash160, Hash};
+use secp256k1::{PublicKey, Secp256k1};
+use sha2::{Digest, Sha256};
+
+use crate::bech32;
+use crate::chain::{self, ChainAccount, ChainAdapter};
+use crate::error::WalletError;
+use crate::secret::Secret;
+
+/// Cosmos Hub 及大多数 Cosmos SDK 链使用的 SLIP-44 币种编号
+pub const DEFAULT_COIN_TYPE: u32 = 118;
+
+/// 内置链的 (bech32 前缀, 币种编号)，其他链可通过自定义前缀派生
+pub fn preset(chain_type: &str) -> Option<(&'static str, u32)> {
+    match chain_type.to_lowercase().as_str() {
+        "cosmos" | "cosmoshub" | "atom" => Some(("cosmos", DEFAULT_COIN_TYPE)),
+        "osmosis" | "osmo" => Some(("osmo", DEFAULT_COIN_TYPE)),
+        "juno" => Some(("juno", DEFAULT_COIN_TYPE)),
+        "akash" => Some(("akash", DEFAULT_COIN_TYPE)),
+        "stargaze" => Some(("stars", DEFAULT_COIN_TYPE)),
+        _ => None,
+    }
+}
+
+/// 校验 bech32 前缀：只允许小写字母和数字
+pub fn validate_prefix(hrp: &str) -> Result<(), String> {
+    if hrp.is_empty()
+        || !hrp
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+    {
+        return Err(format!("Invalid bech32 address prefix: '{}'", hrp));
+    }
+    Ok(())
+}
+
+/// 由压缩公钥生成 bech32 地址
+pub fn encode_address(public_key: &PublicKey, hrp: &str) -> Result<String, String> {
+    validate_prefix(hrp)?;
+    let key_hash = hash160::Hash::hash(&public_key.serialize()).to_byte_array();
+    let data = bech32::convert_bits(&key_hash, 8, 5, true)?;
+    bech32::encode(hrp, &data, bech32::Variant::Bech32)
+}
+
+/// 校验指定前缀的地址：账户地址为20字节，模块账户等合约地址为32字节
+pub fn validate_address(address: &str, hrp: &str) -> Result<(), String> {
+    let (decoded_hrp, data, variant) = bech32::decode(address.trim())?;
+    if decoded_hrp != hrp {
+        return Err(format!(
+            "Address prefix mismatch: expected '{}', got '{}'",
+            hrp, decoded_hrp
+        ));
+    }
+    if variant != bech32::Variant::Bech32 {
+        return Err("Cosmos addresses must use bech32, not bech32m".to_string());
+    }
+    let bytes = bech32::convert_bits(&data, 5, 8, false)?;
+    if bytes.len() != 20 && bytes.len() != 32 {
+        return Err(format!("Invalid address length: {} bytes", bytes.len()));
+    }
+    Ok(())
+}
+
+/// Cosmos 适配器：密钥为不带0x前缀的十六进制，签名为 SHA-256(message) 上的 r || s
+pub struct CosmosAdapter {
+    prefix: String,
+    coin_type: u32,
+}
+
+impl CosmosAdapter {
+    pub fn new(prefix: &str, coin_type: u32) -> Result<Self, WalletError> {
+        validate_prefix(prefix).map_err(WalletError::invalid_argument)?;
+        Ok(CosmosAdapter {
+            prefix: prefix.to_string(),
+            coin_type,
+        })
+    }
+}
+
+pub fn adapter(chain_type: &str) -> Result<Option<Box<dyn ChainAdapter>>, WalletError> {
+    match preset(chain_type) {
+        Some((prefix, coin_type)) => Ok(Some(Box::new(CosmosAdapter::new(prefix, coin_type)?))),
+        None => Ok(None),
+    }
+}
+
+impl ChainAdapter for CosmosAdapter {
+    fn name(&self) -> &'static str {
+        "cosmos"
+    }
+
+    /// `m/44'/coin_type'/0'/0/index`
+    fn account_path(&self, index: u32) -> String {
+        format!("m/44'/{}'/0'/0/{}", self.coin_type, index)
+    }
+
+    fn derive_account(&self, seed: &[u8], path: &str) -> Result<ChainAccount, WalletError> {
+        let secret_key = chain::derive_secp256k1(seed, path)?;
+        let public = PublicKey::from_secret_key(&Secp256k1::signing_only(), &secret_key);
+        Ok(ChainAccount {
+            public_key: hex::encode(public.serialize()),
+            private_key: Secret::new(hex::encode(secret_key.secret_bytes())),
+            address: encode_address(&public, &self.prefix).map_err(WalletError::derivation)?,
+        })
+    }
+
+    fn format_address(&self, public_key: &[u8]) -> Result<String, WalletError> {
+        let public = PublicKey::from_slice(public_key).map_err(|e| {
+            WalletError::invalid_key(format!("Invalid secp256k1 public key: {}", e))
+        })?;
+        encode_address(&public, &self.prefix).map_err(WalletError::invalid_argument)
+    }
+
+    /// 账户地址为20字节，模块账户等合约地址为32字节
+    fn validate_address(&self, address: &str) -> Result<(), WalletError> {
+        validate_address(address, &self.prefix).map_err(WalletError::invalid_address)
+    }
+
+    fn sign(&self, private_key: &str, message: &[u8]) -> Result<Vec<u8>, WalletError> {
+        let secret_key = chain::parse_secp256k1_secret(private_key)?;
+        let signature = chain::sign_recoverable(&secret_key, Sha256::digest(message).into())?;
+        Ok(signature[..64].to_vec())
+    }
+
+    fn verify(
+        &self,
+        public_key: &str,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, WalletError> {
+        let public = chain::parse_secp256k1_public(public_key)?;
+        chain::verify_ecdsa(&public, Sha256::digest(message).into(), signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn derives_hub_address() {
+        let adapter = CosmosAdapter::new("cosmos", DEFAULT_COIN_TYPE).unwrap();
+        let seed = crate::mnemonic::mnemonic_to_seed(PHRASE, "");
+        let account = adapter
+            .derive_account(&seed, &adapter.default_path())
+            .unwrap();
+        assert_eq!(
+            account.address,
+            "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4"
+        );
+        adapter.validate_address(&account.address).unwrap();
+        let osmosis = CosmosAdapter::new("osmo", DEFAULT_COIN_TYPE).unwrap();
+        assert!(osmosis.validate_address(&account.address).is_err());
+    }
+}
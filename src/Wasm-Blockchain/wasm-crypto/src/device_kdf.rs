@@ -0,0 +1,290 @@
+//! 设备ID密钥派生方案
+//!
+//! v1（旧版）：对设备ID做一次无盐 SHA-256，62^10 的空间可以在普通电脑上穷举，
+//! 仅为兼容已有用户的地址而保留；v1 钱包的账户由 `LegacyDeviceAdapter` 按旧版算法派生。
+//! v2：Argon2id（内存困难）+ 每次安装随机生成的盐 + 域分隔标签。
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use sha2::{Digest, Sha256};
+use sp_core::{ecdsa, Pair};
+
+use crate::chain::{self, ChainAccount, ChainAdapter};
+use crate::error::WalletError;
+use crate::ethereum;
+use crate::secret::Secret;
+
+/// v2 方案的域分隔标签，防止同一设备ID在其他用途中派生出相同的熵
+const DOMAIN_TAG: &[u8] = b"aurora-wallet/device-id/v2";
+
+/// Argon2id 参数：19 MiB 内存、2 轮迭代、单线程（OWASP 推荐的最低配置）
+const ARGON2_MEMORY_KIB: u32 = 19 * 1024;
+const ARGON2_ITERATIONS: u32 = 2;
+const ARGON2_PARALLELISM: u32 = 1;
+
+/// 安装盐的长度范围（字节）
+pub const MIN_SALT_LEN: usize = 16;
+pub const MAX_SALT_LEN: usize = 64;
+
+/// 设备ID派生方案版本
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeviceKdfScheme {
+    /// v1：SHA-256(device_id)，无盐
+    LegacySha256,
+    /// v2：Argon2id(tag || device_id, salt)
+    Argon2id,
+}
+
+impl DeviceKdfScheme {
+    pub fn from_version(version: u32) -> Result<Self, WalletError> {
+        match version {
+            1 => Ok(DeviceKdfScheme::LegacySha256),
+            2 => Ok(DeviceKdfScheme::Argon2id),
+            _ => Err(WalletError::invalid_argument(format!(
+                "Unsupported device key derivation scheme: v{}",
+                version
+            ))),
+        }
+    }
+
+    pub fn version(self) -> u32 {
+        match self {
+            DeviceKdfScheme::LegacySha256 => 1,
+            DeviceKdfScheme::Argon2id => 2,
+        }
+    }
+}
+
+/// 按指定方案从设备ID派生32字节熵
+///
+/// v2 方案必须提供安装盐；v1 方案忽略盐。
+pub fn derive_device_entropy(
+    scheme: DeviceKdfScheme,
+    device_id: &str,
+    salt: Option<&[u8]>,
+) -> Result<[u8; 32], WalletError> {
+    let mut output = [0u8; 32];
+    match scheme {
+        DeviceKdfScheme::LegacySha256 => {
+            output.copy_from_slice(&Sha256::digest(device_id.as_bytes()));
+        }
+        DeviceKdfScheme::Argon2id => {
+            let salt = salt.ok_or_else(|| {
+                WalletError::invalid_argument("Scheme v2 requires an installation salt")
+            })?;
+            if !(MIN_SALT_LEN..=MAX_SALT_LEN).contains(&salt.len()) {
+                return Err(WalletError::invalid_argument(format!(
+                    "Installation salt must be between {} and {} bytes, got {}",
+                    MIN_SALT_LEN,
+                    MAX_SALT_LEN,
+                    salt.len()
+                )));
+            }
+
+            let params = Params::new(
+                ARGON2_MEMORY_KIB,
+                ARGON2_ITERATIONS,
+                ARGON2_PARALLELISM,
+                Some(output.len()),
+            )
+            .map_err(|e| WalletError::crypto(format!("Invalid Argon2 parameters: {}", e)))?;
+
+            // 标签与设备ID之间用 0x00 分隔
+            let mut password = Vec::with_capacity(DOMAIN_TAG.len() + 1 + device_id.len());
+            password.extend_from_slice(DOMAIN_TAG);
+            password.push(0);
+            password.extend_from_slice(device_id.as_bytes());
+
+            Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+                .hash_password_into(&password, salt, &mut output)
+                .map_err(|e| WalletError::crypto(format!("Argon2id derivation failed: {}", e)))?;
+        }
+    }
+    Ok(output)
+}
+
+/// 生成新的随机安装盐
+pub fn generate_salt() -> Result<[u8; MIN_SALT_LEN], WalletError> {
+    let mut salt = [0u8; MIN_SALT_LEN];
+    getrandom::getrandom(&mut salt).map_err(|e| WalletError::RandomnessUnavailable {
+        reason: format!("Failed to obtain secure random salt: {}", e),
+    })?;
+    Ok(salt)
+}
+
+/// v1 钱包的账户派生，结果与旧版 `generate_wallet_from_device_id` 逐字节一致
+///
+/// 种子为 SHA-256(助记词)，直接作为 sp-core ecdsa 私钥，不经过 BIP39/BIP32。
+/// 以太坊的公钥为 0x04 || 压缩公钥，地址为 Keccak-256(压缩公钥) 的后20字节（均沿用旧版格式）；
+/// 其他链类型的公钥和地址都是压缩公钥的十六进制。每个钱包只有一个账户，不支持路径和 BIP39 口令。
+pub struct LegacyDeviceAdapter {
+    ethereum: bool,
+}
+
+impl LegacyDeviceAdapter {
+    /// 空链类型与旧版一样按以太坊处理
+    pub fn new(chain_type: &str) -> Self {
+        let chain_type = chain_type.trim();
+        LegacyDeviceAdapter {
+            ethereum: chain_type.is_empty() || chain_type.eq_ignore_ascii_case("ethereum"),
+        }
+    }
+
+    /// 解析旧版导出的公钥：压缩公钥，或以太坊格式的 0x04 || 压缩公钥
+    fn parse_public(public_key: &str) -> Result<ecdsa::Public, WalletError> {
+        let bytes = chain::decode_hex(public_key)
+            .map_err(|e| WalletError::invalid_key(format!("Invalid hex public key: {}", e)))?;
+        let compressed = match bytes.split_first() {
+            Some((0x04, rest)) if rest.len() == 33 => rest,
+            _ => &bytes[..],
+        };
+        let public = secp256k1::PublicKey::from_slice(compressed).map_err(|e| {
+            WalletError::invalid_key(format!("Invalid secp256k1 public key: {}", e))
+        })?;
+        Ok(ecdsa::Public::from_raw(public.serialize()))
+    }
+}
+
+impl ChainAdapter for LegacyDeviceAdapter {
+    fn name(&self) -> &'static str {
+        "legacy-device"
+    }
+
+    fn account_path(&self, _index: u32) -> String {
+        String::new()
+    }
+
+    fn seed(&self, mnemonic_words: &str, passphrase: &str) -> Result<Secret<Vec<u8>>, WalletError> {
+        if !passphrase.is_empty() {
+            return Err(WalletError::unsupported(
+                "Scheme v1 wallets do not support a BIP39 passphrase",
+            ));
+        }
+        Ok(Secret::new(
+            Sha256::digest(mnemonic_words.as_bytes()).to_vec(),
+        ))
+    }
+
+    fn derive_account(&self, seed: &[u8], path: &str) -> Result<ChainAccount, WalletError> {
+        if !path.is_empty() {
+            return Err(WalletError::invalid_path(
+                path,
+                "Scheme v1 wallets have a single account without a derivation path",
+            ));
+        }
+        let pair = ecdsa::Pair::from_seed_slice(seed)
+            .map_err(|e| WalletError::derivation(format!("Failed to create key pair: {:?}", e)))?;
+        let public = pair.public();
+        let private_key = hex::encode(pair.to_raw_vec());
+        let address = self.format_address(public.as_ref())?;
+        Ok(if self.ethereum {
+            ChainAccount {
+                public_key: format!("0x04{}", hex::encode(public.as_ref() as &[u8])),
+                private_key: Secret::new(format!("0x{}", private_key)),
+                address,
+            }
+        } else {
+            ChainAccount {
+                public_key: hex::encode(public.as_ref() as &[u8]),
+                private_key: Secret::new(private_key),
+                address,
+            }
+        })
+    }
+
+    /// 公钥为33字节压缩公钥
+    fn format_address(&self, public_key: &[u8]) -> Result<String, WalletError> {
+        if public_key.len() != 33 {
+            return Err(WalletError::invalid_key(format!(
+                "Compressed public key must be 33 bytes, got {}",
+                public_key.len()
+            )));
+        }
+        Ok(if self.ethereum {
+            // 压缩公钥不以 0x04 开头，整体参与哈希
+            format!(
+                "0x{}",
+                hex::encode(ethereum::public_key_to_address(public_key))
+            )
+        } else {
+            hex::encode(public_key)
+        })
+    }
+
+    fn validate_address(&self, address: &str) -> Result<(), WalletError> {
+        let bytes = chain::decode_hex(address).map_err(WalletError::invalid_address)?;
+        let valid = if self.ethereum {
+            address.starts_with("0x") && bytes.len() == 20
+        } else {
+            secp256k1::PublicKey::from_slice(&bytes).is_ok() && bytes.len() == 33
+        };
+        if !valid {
+            return Err(WalletError::invalid_address(format!(
+                "Invalid scheme v1 address: {}",
+                address
+            )));
+        }
+        Ok(())
+    }
+
+    /// 与旧版 `sign_message` 相同：sp-core ecdsa（消息先做 blake2_256），返回65字节签名
+    fn sign(&self, private_key: &str, message: &[u8]) -> Result<Vec<u8>, WalletError> {
+        let secret =
+            Secret::new(chain::decode_hex(private_key).map_err(|e| {
+                WalletError::invalid_key(format!("Invalid hex private key: {}", e))
+            })?);
+        let pair = ecdsa::Pair::from_seed_slice(secret.expose())
+            .map_err(|e| WalletError::invalid_key(format!("Failed to create key pair: {:?}", e)))?;
+        Ok(pair.sign(message).0.to_vec())
+    }
+
+    fn verify(
+        &self,
+        public_key: &str,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, WalletError> {
+        let public = Self::parse_public(public_key)?;
+        let signature = <[u8; 65]>::try_from(signature).map_err(|_| {
+            WalletError::invalid_signature(format!(
+                "Signature must be 65 bytes, got {}",
+                signature.len()
+            ))
+        })?;
+        Ok(ecdsa::Pair::verify(
+            &ecdsa::Signature::from_raw(signature),
+            message,
+            &public,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_scheme_is_unsalted_sha256() {
+        let entropy =
+            derive_device_entropy(DeviceKdfScheme::LegacySha256, "abcDEF1234", None).unwrap();
+        assert_eq!(entropy.as_slice(), Sha256::digest(b"abcDEF1234").as_slice());
+    }
+
+    #[test]
+    fn argon2id_requires_salt() {
+        let salt = [1u8; MIN_SALT_LEN];
+        let first =
+            derive_device_entropy(DeviceKdfScheme::Argon2id, "abcDEF1234", Some(&salt)).unwrap();
+        let other = derive_device_entropy(
+            DeviceKdfScheme::Argon2id,
+            "abcDEF1234",
+            Some(&[2u8; MIN_SALT_LEN]),
+        )
+        .unwrap();
+        assert_ne!(first, other);
+        assert!(derive_device_entropy(DeviceKdfScheme::Argon2id, "abcDEF1234", None).is_err());
+        assert!(
+            derive_device_entropy(DeviceKdfScheme::Argon2id, "abcDEF1234", Some(&[1u8; 8]))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,341 @@
+//! EIP-712 结构化数据哈希
+//!
+//! 输入为 `eth_signTypedData_v4` 的 JSON（`types`、`primaryType`、`domain`、`message`），
+//! 摘要为 Keccak-256(0x19 0x01 || domainSeparator || hashStruct(message))。
+//! 嵌套结构体按 hashStruct 编码，数组为各元素编码拼接后的哈希，`string` 和 `bytes` 取哈希，
+//! 其余原子类型编码为32字节。`types` 中没有 `EIP712Domain` 时按 `domain` 中出现的字段推断。
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::chain;
+use crate::ethereum::{encode_integer, keccak256};
+
+const DOMAIN_TYPE: &str = "EIP712Domain";
+
+/// 推断 `EIP712Domain` 时的字段及顺序
+const DOMAIN_FIELDS: [(&str, &str); 5] = [
+    ("name", "string"),
+    ("version", "string"),
+    ("chainId", "uint256"),
+    ("verifyingContract", "address"),
+    ("salt", "bytes32"),
+];
+
+/// 结构体的一个字段
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct TypedField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+/// `eth_signTypedData_v4` 的请求体
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypedData {
+    pub types: BTreeMap<String, Vec<TypedField>>,
+    pub primary_type: String,
+    #[serde(default)]
+    pub domain: Map<String, Value>,
+    #[serde(default)]
+    pub message: Value,
+}
+
+/// 签名的摘要及中间哈希，供界面展示签名内容
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TypedDataHash {
+    pub domain_separator: [u8; 32],
+    /// `hashStruct(message)`；主类型为 `EIP712Domain` 时为 None
+    pub struct_hash: Option<[u8; 32]>,
+    pub digest: [u8; 32],
+}
+
+impl TypedData {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let mut typed_data: TypedData =
+            serde_json::from_str(json).map_err(|e| format!("Invalid EIP-712 JSON: {}", e))?;
+        if !typed_data.types.contains_key(DOMAIN_TYPE) {
+            let fields = DOMAIN_FIELDS
+                .iter()
+                .filter(|(name, _)| typed_data.domain.contains_key(*name))
+                .map(|(name, kind)| TypedField {
+                    name: name.to_string(),
+                    kind: kind.to_string(),
+                })
+                .collect();
+            typed_data.types.insert(DOMAIN_TYPE.to_string(), fields);
+        }
+        if !typed_data.types.contains_key(&typed_data.primary_type) {
+            return Err(format!(
+                "Primary type '{}' is not defined in types",
+                typed_data.primary_type
+            ));
+        }
+        Ok(typed_data)
+    }
+
+    /// 计算域分隔符、消息结构哈希和最终摘要
+    pub fn hash(&self) -> Result<TypedDataHash, String> {
+        let domain = Value::Object(self.domain.clone());
+        let domain_separator = self.hash_struct(DOMAIN_TYPE, &domain)?;
+
+        let mut preimage = vec![0x19, 0x01];
+        preimage.extend_from_slice(&domain_separator);
+        let struct_hash = if self.primary_type == DOMAIN_TYPE {
+            None
+        } else {
+            let hash = self.hash_struct(&self.primary_type, &self.message)?;
+            preimage.extend_from_slice(&hash);
+            Some(hash)
+        };
+
+        Ok(TypedDataHash {
+            domain_separator,
+            struct_hash,
+            digest: keccak256(&preimage),
+        })
+    }
+
+    /// `encodeType`：主类型在前，其余引用到的结构体按名称排序
+    pub fn encode_type(&self, primary: &str) -> Result<String, String> {
+        let mut dependencies = BTreeSet::new();
+        self.collect_dependencies(primary, &mut dependencies)?;
+        dependencies.remove(primary);
+
+        let mut encoded = String::new();
+        for name in std::iter::once(primary).chain(dependencies.iter().map(String::as_str)) {
+            let fields = self.struct_fields(name)?;
+            let members: Vec<String> = fields
+                .iter()
+                .map(|field| format!("{} {}", field.kind, field.name))
+                .collect();
+            encoded.push_str(&format!("{}({})", name, members.join(",")));
+        }
+        Ok(encoded)
+    }
+
+    pub fn type_hash(&self, primary: &str) -> Result<[u8; 32], String> {
+        Ok(keccak256(self.encode_type(primary)?.as_bytes()))
+    }
+
+    /// `hashStruct`：Keccak-256(typeHash || encodeData)
+    pub fn hash_struct(&self, name: &str, value: &Value) -> Result<[u8; 32], String> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| format!("Value of type '{}' must be an object", name))?;
+
+        let mut encoded = self.type_hash(name)?.to_vec();
+        for field in self.struct_fields(name)? {
+            let value = object.get(&field.name).ok_or_else(|| {
+                format!(
+                    "Missing value for field '{}' of type '{}'",
+                    field.name, name
+                )
+            })?;
+            let word = self
+                .encode_value(&field.kind, value)
+                .map_err(|e| format!("{}.{}: {}", name, field.name, e))?;
+            encoded.extend_from_slice(&word);
+        }
+        Ok(keccak256(&encoded))
+    }
+
+    fn struct_fields(&self, name: &str) -> Result<&[TypedField], String> {
+        self.types
+            .get(name)
+            .map(Vec::as_slice)
+            .ok_or_else(|| format!("Type '{}' is not defined in types", name))
+    }
+
+    fn collect_dependencies(&self, name: &str, found: &mut BTreeSet<String>) -> Result<(), String> {
+        if !found.insert(name.to_string()) {
+            return Ok(());
+        }
+        for field in self.struct_fields(name)? {
+            let base = base_type(&field.kind);
+            if self.types.contains_key(base) {
+                self.collect_dependencies(base, found)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 将一个字段编码为32字节
+    fn encode_value(&self, kind: &str, value: &Value) -> Result<[u8; 32], String> {
+        if let Some((element, length)) = split_array(kind)? {
+            let items = value
+                .as_array()
+                .ok_or_else(|| format!("Value of type '{}' must be an array", kind))?;
+            if let Some(length) = length {
+                if items.len() != length {
+                    return Err(format!(
+                        "Array of type '{}' must have {} elements, got {}",
+                        kind,
+                        length,
+                        items.len()
+                    ));
+                }
+            }
+            let mut encoded = Vec::with_capacity(items.len() * 32);
+            for item in items {
+                encoded.extend_from_slice(&self.encode_value(element, item)?);
+            }
+            return Ok(keccak256(&encoded));
+        }
+
+        if self.types.contains_key(kind) {
+            return self.hash_struct(kind, value);
+        }
+        encode_atomic(kind, value)
+    }
+}
+
+/// 去掉所有数组后缀，如 `Person[][2]` 得到 `Person`
+fn base_type(kind: &str) -> &str {
+    kind.split('[').next().unwrap_or(kind)
+}
+
+/// 拆分最外层数组：`T[]` 得到 (T, None)，`T[n]` 得到 (T, Some(n))，非数组返回 None
+fn split_array(kind: &str) -> Result<Option<(&str, Option<usize>)>, String> {
+    let body = match kind.strip_suffix(']') {
+        Some(body) => body,
+        None => return Ok(None),
+    };
+    let open = body
+        .rfind('[')
+        .ok_or_else(|| format!("Invalid array type: '{}'", kind))?;
+    let (element, length) = (&body[..open], &body[open + 1..]);
+    if length.is_empty() {
+        return Ok(Some((element, None)));
+    }
+    length
+        .parse()
+        .map(|length| Some((element, Some(length))))
+        .map_err(|_| format!("Invalid array length in type '{}'", kind))
+}
+
+/// 编码原子类型和 `string`、`bytes`
+fn encode_atomic(kind: &str, value: &Value) -> Result<[u8; 32], String> {
+    let mut word = [0u8; 32];
+    match kind {
+        "string" => {
+            let text = value
+                .as_str()
+                .ok_or_else(|| "Value of type 'string' must be a string".to_string())?;
+            Ok(keccak256(text.as_bytes()))
+        }
+        "bytes" => Ok(keccak256(&decode_bytes(value)?)),
+        "bool" => {
+            word[31] = match value {
+                Value::Bool(flag) => u8::from(*flag),
+                Value::String(text) if text == "true" || text == "false" => {
+                    u8::from(text == "true")
+                }
+                _ => return Err("Value of type 'bool' must be a boolean".to_string()),
+            };
+            Ok(word)
+        }
+        "address" => {
+            let bytes = decode_bytes(value)?;
+            if bytes.len() != 20 {
+                return Err(format!("Address must be 20 bytes, got {}", bytes.len()));
+            }
+            word[12..].copy_from_slice(&bytes);
+            Ok(word)
+        }
+        _ => {
+            if let Some(size) = kind.strip_prefix("bytes") {
+                let size = parse_size(kind, size, 1, 32)?;
+                let bytes = decode_bytes(value)?;
+                if bytes.len() != size {
+                    return Err(format!(
+                        "Value of type '{}' must be {} bytes, got {}",
+                        kind,
+                        size,
+                        bytes.len()
+                    ));
+                }
+                word[..size].copy_from_slice(&bytes);
+                Ok(word)
+            } else if let Some(bits) = kind.strip_prefix("uint") {
+                encode_integer(value, parse_int_bits(kind, bits)?, false)
+            } else if let Some(bits) = kind.strip_prefix("int") {
+                encode_integer(value, parse_int_bits(kind, bits)?, true)
+            } else {
+                Err(format!("Unknown type: '{}'", kind))
+            }
+        }
+    }
+}
+
+fn parse_size(kind: &str, size: &str, min: usize, max: usize) -> Result<usize, String> {
+    match size.parse() {
+        Ok(parsed) if (min..=max).contains(&parsed) && !size.starts_with('0') => Ok(parsed),
+        _ => Err(format!("Unknown type: '{}'", kind)),
+    }
+}
+
+/// 整数位宽为 8 到 256 之间 8 的倍数
+fn parse_int_bits(kind: &str, bits: &str) -> Result<usize, String> {
+    let bits = parse_size(kind, bits, 8, 256)?;
+    if bits % 8 != 0 {
+        return Err(format!("Unknown type: '{}'", kind));
+    }
+    Ok(bits)
+}
+
+/// 解码十六进制字节串，允许带0x前缀
+fn decode_bytes(value: &Value) -> Result<Vec<u8>, String> {
+    let text = value
+        .as_str()
+        .ok_or_else(|| "Bytes value must be a hex string".to_string())?;
+    chain::decode_hex(text).map_err(|e| format!("Invalid hex value: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{chain, ethereum};
+
+    // EIP-712 规范中的 Mail 示例
+    const MAIL: &str = r#"{"types":{"EIP712Domain":[{"name":"name","type":"string"},{"name":"version","type":"string"},{"name":"chainId","type":"uint256"},{"name":"verifyingContract","type":"address"}],"Person":[{"name":"name","type":"string"},{"name":"wallet","type":"address"}],"Mail":[{"name":"from","type":"Person"},{"name":"to","type":"Person"},{"name":"contents","type":"string"}]},"primaryType":"Mail","domain":{"name":"Ether Mail","version":"1","chainId":1,"verifyingContract":"0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"},"message":{"from":{"name":"Cow","wallet":"0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"},"to":{"name":"Bob","wallet":"0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"},"contents":"Hello, Bob!"}}"#;
+
+    #[test]
+    fn hashes_mail_example() {
+        let typed_data = TypedData::from_json(MAIL).unwrap();
+        assert_eq!(
+            typed_data.encode_type("Mail").unwrap(),
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+        let hash = typed_data.hash().unwrap();
+        assert_eq!(
+            hex::encode(hash.domain_separator),
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+        assert_eq!(
+            hex::encode(hash.struct_hash.unwrap()),
+            "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+        );
+        assert_eq!(
+            hex::encode(hash.digest),
+            "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+        );
+    }
+
+    #[test]
+    fn signs_mail_example() {
+        // 规范中的私钥 keccak256("cow")
+        let secret_key = chain::parse_secp256k1_secret(
+            "c85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4",
+        )
+        .unwrap();
+        let digest = TypedData::from_json(MAIL).unwrap().hash().unwrap().digest;
+        assert_eq!(
+            hex::encode(ethereum::sign_digest(&secret_key, digest).unwrap()),
+            "4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b915621c"
+        );
+    }
+}
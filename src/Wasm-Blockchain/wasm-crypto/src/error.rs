@@ -0,0 +1,243 @@
+//! 钱包错误类型
+//!
+//! 每个变体对应一个稳定的错误码（如 `INVALID_MNEMONIC_WORD`），传给 JS 时为 `name` 为
+//! `WalletError` 的 `Error` 对象，并带有 `code` 和 `details` 字段，调用方应按错误码分支，
+//! 而不是解析错误信息。编码、校验类的底层模块仍返回 `String`，由调用处归类为具体错误码。
+
+use std::fmt;
+
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen(typescript_custom_section)]
+const TS_WALLET_ERROR: &'static str = r#"
+export type WalletErrorCode =
+    | 'EMPTY_INPUT'
+    | 'INVALID_WORD_COUNT'
+    | 'INVALID_MNEMONIC_WORD'
+    | 'BAD_CHECKSUM'
+    | 'INVALID_MNEMONIC'
+    | 'UNSUPPORTED_LANGUAGE'
+    | 'UNSUPPORTED_CHAIN'
+    | 'UNSUPPORTED_OPERATION'
+    | 'INVALID_DERIVATION_PATH'
+    | 'DERIVATION_FAILED'
+    | 'INVALID_KEY_FORMAT'
+    | 'INVALID_ADDRESS'
+    | 'INVALID_SIGNATURE'
+    | 'INVALID_ARGUMENT'
+    | 'RANDOMNESS_UNAVAILABLE'
+    | 'CRYPTO_FAILURE'
+    | 'SERIALIZATION_FAILED';
+
+export interface WalletError extends Error {
+    name: 'WalletError';
+    code: WalletErrorCode;
+    details: Record<string, unknown>;
+}
+"#;
+
+/// 钱包操作的错误，字段即 JS 端 `details` 的内容
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged, rename_all_fields = "camelCase")]
+pub enum WalletError {
+    /// 必填输入为空
+    EmptyInput {
+        field: String,
+    },
+    /// 助记词单词数量不是 12/15/18/21/24
+    InvalidWordCount {
+        count: usize,
+    },
+    /// 单词不在任何词表中，位置从1开始
+    InvalidMnemonicWord {
+        position: usize,
+        word: String,
+    },
+    /// 助记词校验和错误
+    BadChecksum {
+        language: String,
+    },
+    /// 其他助记词错误
+    InvalidMnemonic {
+        reason: String,
+    },
+    UnsupportedLanguage {
+        language: String,
+    },
+    UnsupportedChain {
+        chain_type: String,
+    },
+    /// 该链或地址类型不支持的操作
+    UnsupportedOperation {
+        reason: String,
+    },
+    InvalidDerivationPath {
+        path: String,
+        reason: String,
+    },
+    DerivationFailed {
+        reason: String,
+    },
+    InvalidKeyFormat {
+        reason: String,
+    },
+    InvalidAddress {
+        reason: String,
+    },
+    InvalidSignature {
+        reason: String,
+    },
+    /// 参数超出范围或格式错误
+    InvalidArgument {
+        reason: String,
+    },
+    RandomnessUnavailable {
+        reason: String,
+    },
+    CryptoFailure {
+        reason: String,
+    },
+    SerializationFailed {
+        reason: String,
+    },
+}
+
+impl WalletError {
+    /// 稳定的错误码，与 TypeScript 中的 `WalletErrorCode` 一致
+    pub fn code(&self) -> &'static str {
+        match self {
+            WalletError::EmptyInput { .. } => "EMPTY_INPUT",
+            WalletError::InvalidWordCount { .. } => "INVALID_WORD_COUNT",
+            WalletError::InvalidMnemonicWord { .. } => "INVALID_MNEMONIC_WORD",
+            WalletError::BadChecksum { .. } => "BAD_CHECKSUM",
+            WalletError::InvalidMnemonic { .. } => "INVALID_MNEMONIC",
+            WalletError::UnsupportedLanguage { .. } => "UNSUPPORTED_LANGUAGE",
+            WalletError::UnsupportedChain { .. } => "UNSUPPORTED_CHAIN",
+            WalletError::UnsupportedOperation { .. } => "UNSUPPORTED_OPERATION",
+            WalletError::InvalidDerivationPath { .. } => "INVALID_DERIVATION_PATH",
+            WalletError::DerivationFailed { .. } => "DERIVATION_FAILED",
+            WalletError::InvalidKeyFormat { .. } => "INVALID_KEY_FORMAT",
+            WalletError::InvalidAddress { .. } => "INVALID_ADDRESS",
+            WalletError::InvalidSignature { .. } => "INVALID_SIGNATURE",
+            WalletError::InvalidArgument { .. } => "INVALID_ARGUMENT",
+            WalletError::RandomnessUnavailable { .. } => "RANDOMNESS_UNAVAILABLE",
+            WalletError::CryptoFailure { .. } => "CRYPTO_FAILURE",
+            WalletError::SerializationFailed { .. } => "SERIALIZATION_FAILED",
+        }
+    }
+
+    pub fn empty_input(field: &str) -> Self {
+        WalletError::EmptyInput {
+            field: field.to_string(),
+        }
+    }
+
+    pub fn invalid_path(path: &str, reason: impl Into<String>) -> Self {
+        WalletError::InvalidDerivationPath {
+            path: path.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn derivation(reason: impl Into<String>) -> Self {
+        WalletError::DerivationFailed {
+            reason: reason.into(),
+        }
+    }
+
+    pub fn invalid_key(reason: impl Into<String>) -> Self {
+        WalletError::InvalidKeyFormat {
+            reason: reason.into(),
+        }
+    }
+
+    pub fn invalid_address(reason: impl Into<String>) -> Self {
+        WalletError::InvalidAddress {
+            reason: reason.into(),
+        }
+    }
+
+    pub fn invalid_signature(reason: impl Into<String>) -> Self {
+        WalletError::InvalidSignature {
+            reason: reason.into(),
+        }
+    }
+
+    pub fn invalid_argument(reason: impl Into<String>) -> Self {
+        WalletError::InvalidArgument {
+            reason: reason.into(),
+        }
+    }
+
+    pub fn unsupported(reason: impl Into<String>) -> Self {
+        WalletError::UnsupportedOperation {
+            reason: reason.into(),
+        }
+    }
+
+    pub fn crypto(reason: impl Into<String>) -> Self {
+        WalletError::CryptoFailure {
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for WalletError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WalletError::EmptyInput { field } => write!(f, "{} is empty", field),
+            WalletError::InvalidWordCount { count } => write!(
+                f,
+                "Mnemonic must contain 12, 15, 18, 21 or 24 words, got {}",
+                count
+            ),
+            WalletError::InvalidMnemonicWord { position, word } => {
+                write!(f, "Unknown word at position {}: '{}'", position, word)
+            }
+            WalletError::BadChecksum { language } => {
+                write!(f, "Invalid mnemonic checksum ({})", language)
+            }
+            WalletError::InvalidMnemonic { reason } => write!(f, "Invalid mnemonic: {}", reason),
+            WalletError::UnsupportedLanguage { language } => {
+                write!(f, "Unsupported mnemonic language: '{}'", language)
+            }
+            WalletError::UnsupportedChain { chain_type } => {
+                write!(f, "Unsupported chain type: '{}'", chain_type)
+            }
+            WalletError::InvalidDerivationPath { path, reason } => {
+                write!(f, "Invalid derivation path '{}': {}", path, reason)
+            }
+            WalletError::UnsupportedOperation { reason }
+            | WalletError::DerivationFailed { reason }
+            | WalletError::InvalidKeyFormat { reason }
+            | WalletError::InvalidAddress { reason }
+            | WalletError::InvalidSignature { reason }
+            | WalletError::InvalidArgument { reason }
+            | WalletError::RandomnessUnavailable { reason }
+            | WalletError::CryptoFailure { reason }
+            | WalletError::SerializationFailed { reason } => f.write_str(reason),
+        }
+    }
+}
+
+impl std::error::Error for WalletError {}
+
+/// 转换为 JS `Error`，附加 `code` 和 `details` 字段
+#[cfg(feature = "wasm")]
+impl From<WalletError> for JsValue {
+    fn from(error: WalletError) -> Self {
+        let js_error = js_sys::Error::new(&error.to_string());
+        js_error.set_name("WalletError");
+
+        let details = error
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .unwrap_or(JsValue::NULL);
+        // 在普通对象上设置属性不会失败
+        let _ = js_sys::Reflect::set(&js_error, &"code".into(), &error.code().into());
+        let _ = js_sys::Reflect::set(&js_error, &"details".into(), &details);
+        js_error.into()
+    }
+}
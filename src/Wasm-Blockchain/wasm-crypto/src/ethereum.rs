@@ -0,0 +1,348 @@
+//! 以太坊地址
+//!
+//! 地址为 Keccak-256(未压缩公钥) 的后20字节，默认按 EIP-55 输出大小写混合的校验和形式；
+//! 传入链ID时使用 EIP-1191 变体（RSK 等链使用）。消息签名（包括适配器的 `sign`）统一使用
+//! EIP-191 `personal_sign`，v 为 27/28，可由 ethers、viem、MetaMask 和合约中的 `ecrecover` 验证。
+
+use secp256k1::{PublicKey, Secp256k1, SecretKey};
+use serde_json::Value;
+use tiny_keccak::{Hasher, Keccak};
+use web3::types::U256;
+
+use crate::chain::{self, ChainAccount, ChainAdapter};
+use crate::error::WalletError;
+use crate::secret::Secret;
+
+/// 地址的十六进制长度（不含0x前缀）
+const ADDRESS_HEX_LEN: usize = 40;
+
+/// EIP-191 personal_sign 的消息前缀，后接十进制消息长度
+const PERSONAL_MESSAGE_PREFIX: &str = "\x19Ethereum Signed Message:\n";
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut keccak = Keccak::v256();
+    let mut hash = [0u8; 32];
+    keccak.update(data);
+    keccak.finalize(&mut hash);
+    hash
+}
+
+/// EIP-191 消息摘要：Keccak-256("\x19Ethereum Signed Message:\n" || 十进制长度 || 消息)
+pub fn personal_message_hash(message: &[u8]) -> [u8; 32] {
+    prefixed_message_hash(PERSONAL_MESSAGE_PREFIX, message)
+}
+
+/// Keccak-256(前缀 || 十进制长度 || 消息)，Tron 等链沿用 EIP-191 的格式但前缀不同
+pub fn prefixed_message_hash(prefix: &str, message: &[u8]) -> [u8; 32] {
+    let mut keccak = Keccak::v256();
+    let mut hash = [0u8; 32];
+    keccak.update(prefix.as_bytes());
+    keccak.update(message.len().to_string().as_bytes());
+    keccak.update(message);
+    keccak.finalize(&mut hash);
+    hash
+}
+
+/// 按以太坊惯例对32字节摘要签名，返回 r || s || v，v 为 27 或 28
+pub fn sign_digest(secret_key: &SecretKey, digest: [u8; 32]) -> Result<[u8; 65], WalletError> {
+    let mut signature = chain::sign_recoverable(secret_key, digest)?;
+    signature[64] += 27;
+    Ok(signature)
+}
+
+/// `personal_sign`：对 EIP-191 摘要签名，返回 r || s || v，v 为 27 或 28
+pub fn personal_sign(secret_key: &SecretKey, message: &[u8]) -> Result<[u8; 65], WalletError> {
+    sign_digest(secret_key, personal_message_hash(message))
+}
+
+/// 以太坊地址的20字节：Keccak-256(未压缩公钥去掉0x04前缀) 的后20字节
+pub fn public_key_to_address(public_key: &[u8]) -> [u8; 20] {
+    // 确保公钥格式正确（去掉0x04前缀）
+    let public_key = match public_key.split_first() {
+        Some((0x04, rest)) => rest,
+        _ => public_key,
+    };
+
+    // 取最后20个字节作为地址
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&keccak256(public_key)[12..]);
+    address
+}
+
+/// 由压缩（33字节）、未压缩（65字节）或去掉前缀的（64字节）公钥计算地址
+pub fn address_from_public_key(public_key: &[u8]) -> Result<[u8; 20], String> {
+    if public_key.len() == 64 {
+        return Ok(public_key_to_address(public_key));
+    }
+    let public = PublicKey::from_slice(public_key)
+        .map_err(|e| format!("Invalid secp256k1 public key: {}", e))?;
+    Ok(public_key_to_address(&public.serialize_uncompressed()))
+}
+
+/// 生成带0x前缀的校验和地址
+///
+/// `chain_id` 为 None 时按 EIP-55 对小写十六进制求哈希；
+/// 否则按 EIP-1191 对 `"{chain_id}0x{小写地址}"` 求哈希。
+pub fn to_checksum_address(address: &[u8; 20], chain_id: Option<u64>) -> String {
+    let lower = hex::encode(address);
+    let hash = match chain_id {
+        Some(chain_id) => keccak256(format!("{}0x{}", chain_id, lower).as_bytes()),
+        None => keccak256(lower.as_bytes()),
+    };
+
+    let checksummed: String = lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!("0x{}", checksummed)
+}
+
+/// 地址校验结果
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressCheck {
+    /// 地址是否带有大小写混合的校验和
+    pub has_checksum: bool,
+    /// 正确的校验和形式
+    pub checksum_address: String,
+}
+
+/// 校验以太坊地址
+///
+/// 全小写或全大写的地址没有校验和：非严格模式下视为有效，严格模式下拒绝。
+/// 大小写混合的地址必须与校验和一致，否则返回包含正确形式的错误。
+pub fn validate_address(
+    address: &str,
+    strict: bool,
+    chain_id: Option<u64>,
+) -> Result<AddressCheck, String> {
+    let body = address
+        .trim()
+        .strip_prefix("0x")
+        .ok_or_else(|| "Address must start with 0x".to_string())?;
+    if body.len() != ADDRESS_HEX_LEN {
+        return Err(format!(
+            "Address must have {} hex characters, got {}",
+            ADDRESS_HEX_LEN,
+            body.len()
+        ));
+    }
+
+    let bytes = hex::decode(body).map_err(|e| format!("Invalid hex address: {}", e))?;
+    let mut raw = [0u8; 20];
+    raw.copy_from_slice(&bytes);
+    let checksum_address = to_checksum_address(&raw, chain_id);
+
+    let has_lower = body.chars().any(|c| c.is_ascii_lowercase());
+    let has_upper = body.chars().any(|c| c.is_ascii_uppercase());
+    let has_checksum = has_lower && has_upper;
+
+    if !has_checksum {
+        if strict {
+            return Err("Address has no checksum (all lowercase or all uppercase)".to_string());
+        }
+    } else if body != &checksum_address[2..] {
+        return Err(format!(
+            "Address checksum mismatch, expected {}",
+            checksum_address
+        ));
+    }
+
+    Ok(AddressCheck {
+        has_checksum,
+        checksum_address,
+    })
+}
+
+/// 解析 JSON 整数：数字，或十进制/0x十六进制字符串（大整数须用字符串），返回 (是否为负, 绝对值)
+pub fn parse_integer(value: &Value) -> Result<(bool, U256), String> {
+    match value {
+        Value::Number(number) => {
+            if let Some(unsigned) = number.as_u64() {
+                Ok((false, U256::from(unsigned)))
+            } else if let Some(signed) = number.as_i64() {
+                Ok((signed < 0, U256::from(signed.unsigned_abs())))
+            } else {
+                Err(format!(
+                    "Integer {} is not exact, pass large integers as strings",
+                    number
+                ))
+            }
+        }
+        Value::String(text) => {
+            let text = text.trim();
+            let (negative, digits) = match text.strip_prefix('-') {
+                Some(digits) => (true, digits),
+                None => (false, text),
+            };
+            let magnitude = match digits.strip_prefix("0x") {
+                Some(hex) if !hex.is_empty() => U256::from_str_radix(hex, 16).ok(),
+                Some(_) => None,
+                None if !digits.is_empty() => U256::from_dec_str(digits).ok(),
+                None => None,
+            }
+            .ok_or_else(|| format!("Invalid integer: '{}'", text))?;
+            Ok((negative && !magnitude.is_zero(), magnitude))
+        }
+        _ => Err("Integer value must be a number or a string".to_string()),
+    }
+}
+
+/// 按位宽检查范围并编码为32字节大端，负数使用二进制补码
+pub fn encode_integer(value: &Value, bits: usize, signed: bool) -> Result<[u8; 32], String> {
+    let (negative, magnitude) = parse_integer(value)?;
+    let in_range = match (signed, negative) {
+        (false, true) => false,
+        (false, false) => magnitude.bits() <= bits,
+        (true, false) => magnitude.bits() < bits,
+        (true, true) => magnitude <= U256::one() << (bits - 1),
+    };
+    if !in_range {
+        let kind = if signed { "int" } else { "uint" };
+        return Err(format!("Integer out of range for {}{}", kind, bits));
+    }
+
+    let word = if negative {
+        (!magnitude).overflowing_add(U256::one()).0
+    } else {
+        magnitude
+    };
+    let mut encoded = [0u8; 32];
+    word.to_big_endian(&mut encoded);
+    Ok(encoded)
+}
+
+/// 以太坊适配器：`m/44'/60'/0'/0/n`，密钥和签名均为带0x前缀的十六进制
+pub struct EthereumAdapter;
+
+pub fn adapter(chain_type: &str) -> Result<Option<Box<dyn ChainAdapter>>, WalletError> {
+    match chain_type.to_lowercase().as_str() {
+        "ethereum" | "eth" => Ok(Some(Box::new(EthereumAdapter))),
+        _ => Ok(None),
+    }
+}
+
+impl ChainAdapter for EthereumAdapter {
+    fn name(&self) -> &'static str {
+        "ethereum"
+    }
+
+    fn account_path(&self, index: u32) -> String {
+        format!("m/44'/60'/0'/0/{}", index)
+    }
+
+    fn derive_account(&self, seed: &[u8], path: &str) -> Result<ChainAccount, WalletError> {
+        let secret_key = chain::derive_secp256k1(seed, path)?;
+        // 获取完整的未压缩公钥（包含0x04前缀）
+        let public = PublicKey::from_secret_key(&Secp256k1::signing_only(), &secret_key)
+            .serialize_uncompressed();
+        Ok(ChainAccount {
+            public_key: format!("0x{}", hex::encode(public)),
+            private_key: Secret::new(format!("0x{}", hex::encode(secret_key.secret_bytes()))),
+            address: self.format_address(&public)?,
+        })
+    }
+
+    fn format_address(&self, public_key: &[u8]) -> Result<String, WalletError> {
+        let address = address_from_public_key(public_key).map_err(WalletError::invalid_key)?;
+        Ok(to_checksum_address(&address, None))
+    }
+
+    fn validate_address(&self, address: &str) -> Result<(), WalletError> {
+        validate_address(address, false, None)
+            .map(|_| ())
+            .map_err(WalletError::invalid_address)
+    }
+
+    /// 与 `personal_sign` 相同：对 EIP-191 摘要签名，返回 r || s || v（v 为 27/28）
+    fn sign(&self, private_key: &str, message: &[u8]) -> Result<Vec<u8>, WalletError> {
+        let secret_key = chain::parse_secp256k1_secret(private_key)?;
+        Ok(personal_sign(&secret_key, message)?.to_vec())
+    }
+
+    fn verify(
+        &self,
+        public_key: &str,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, WalletError> {
+        let public = chain::parse_secp256k1_public(public_key)?;
+        chain::verify_ecdsa(&public, personal_message_hash(message), signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums_eip55_vectors() {
+        for address in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            let check = validate_address(address, true, None).unwrap();
+            assert_eq!(check.checksum_address, address);
+        }
+        assert!(
+            validate_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD", false, None).is_err()
+        );
+        assert!(
+            validate_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed", true, None).is_err()
+        );
+    }
+
+    #[test]
+    fn checksums_eip1191_vectors() {
+        // EIP-1191 中 RSK 主网（chain id 30）的示例
+        for address in [
+            "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD",
+            "0xFb6916095cA1Df60bb79ce92cE3EA74c37c5d359",
+            "0xDBF03B407c01E7CD3cBea99509D93F8Dddc8C6FB",
+            "0xD1220A0Cf47c7B9BE7a2e6ba89F429762E7B9adB",
+        ] {
+            let check = validate_address(address, true, Some(30)).unwrap();
+            assert_eq!(check.checksum_address, address);
+        }
+    }
+
+    const WEB3_KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    #[test]
+    fn personal_sign_matches_web3() {
+        // web3.eth.accounts.sign("Some data", ...) 的文档示例
+        let secret_key = chain::parse_secp256k1_secret(WEB3_KEY).unwrap();
+        assert_eq!(
+            hex::encode(personal_message_hash(b"Some data")),
+            "1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655"
+        );
+        assert_eq!(
+            hex::encode(personal_sign(&secret_key, b"Some data").unwrap()),
+            "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c"
+        );
+    }
+
+    #[test]
+    fn adapter_signs_like_personal_sign() {
+        let signature = EthereumAdapter.sign(WEB3_KEY, b"Some data").unwrap();
+        assert_eq!(
+            hex::encode(&signature),
+            "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c"
+        );
+        let secret_key = chain::parse_secp256k1_secret(WEB3_KEY).unwrap();
+        let public_key = PublicKey::from_secret_key(&Secp256k1::new(), &secret_key);
+        let public_key = hex::encode(public_key.serialize_uncompressed());
+        assert!(EthereumAdapter
+            .verify(&public_key, b"Some data", &signature)
+            .unwrap());
+    }
+}
@@ -0,0 +1,127 @@
+//! 日志
+//!
+//! 级别为 off / error / info / debug，可由 JS 通过 `set_log_level` 或 Rust 通过 `set_level`
+//! 调整。调试构建默认 info，发布构建默认 off；原生构建经由 `log` crate 输出，
+//! 宿主程序未设置 logger 时不输出。
+//!
+//! 日志宏只接受格式化参数，助记词、种子和私钥均以 `Secret` 传递，不实现 `Display`，
+//! 无法写入日志。
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen(typescript_custom_section)]
+const TS_LOG_LEVEL: &'static str = r#"
+export type LogLevel = 'off' | 'error' | 'info' | 'debug';
+"#;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Off = 0,
+    Error = 1,
+    Info = 2,
+    Debug = 3,
+}
+
+/// 调试构建默认输出 info，发布构建默认不输出
+pub const DEFAULT_LEVEL: LogLevel = if cfg!(debug_assertions) {
+    LogLevel::Info
+} else {
+    LogLevel::Off
+};
+
+static LEVEL: AtomicU8 = AtomicU8::new(DEFAULT_LEVEL as u8);
+
+impl LogLevel {
+    /// 解析级别名称（不区分大小写）
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.trim().to_lowercase().as_str() {
+            "off" | "none" => Ok(LogLevel::Off),
+            "error" => Ok(LogLevel::Error),
+            "info" => Ok(LogLevel::Info),
+            "debug" => Ok(LogLevel::Debug),
+            _ => Err(format!("Unsupported log level: '{}'", name)),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            LogLevel::Off => "off",
+            LogLevel::Error => "error",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => LogLevel::Off,
+            1 => LogLevel::Error,
+            2 => LogLevel::Info,
+            _ => LogLevel::Debug,
+        }
+    }
+}
+
+pub fn set_level(level: LogLevel) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> LogLevel {
+    LogLevel::from_u8(LEVEL.load(Ordering::Relaxed))
+}
+
+/// 该级别的日志当前是否输出
+pub fn enabled(level: LogLevel) -> bool {
+    level != LogLevel::Off && level <= self::level()
+}
+
+/// 写入一条日志，wasm 绑定下输出到浏览器控制台
+///
+/// 其他情况交给 `log` crate，由宿主程序的 logger 决定去向。
+pub fn write(level: LogLevel, message: &str) {
+    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+    {
+        let message = JsValue::from_str(&format!("WASM: {}", message));
+        match level {
+            LogLevel::Error => web_sys::console::error_1(&message),
+            LogLevel::Debug => web_sys::console::debug_1(&message),
+            _ => web_sys::console::log_1(&message),
+        }
+    }
+    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+    {
+        let level = match level {
+            LogLevel::Error => log::Level::Error,
+            LogLevel::Info => log::Level::Info,
+            _ => log::Level::Debug,
+        };
+        log::log!(level, "{}", message);
+    }
+}
+
+macro_rules! log_at {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::logging::enabled($level) {
+            $crate::logging::write($level, &format!($($arg)*));
+        }
+    };
+}
+
+// 未启用 wasm 特性时只有原生接口，不一定用到下面的全部级别
+#[allow(unused_macros)]
+macro_rules! log_error {
+    ($($arg:tt)*) => { log_at!($crate::logging::LogLevel::Error, $($arg)*) };
+}
+
+#[allow(unused_macros)]
+macro_rules! log_info {
+    ($($arg:tt)*) => { log_at!($crate::logging::LogLevel::Info, $($arg)*) };
+}
+
+macro_rules! log_debug {
+    ($($arg:tt)*) => { log_at!($crate::logging::LogLevel::Debug, $($arg)*) };
+}
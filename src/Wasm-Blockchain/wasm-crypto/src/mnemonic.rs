@@ -0,0 +1,410 @@
+//! BIP39 助记词相关工具
+//!
+//! 按 BIP39 规范将助记词转换为 64 字节种子：
+//! PBKDF2-HMAC-SHA512，2048 轮，助记词与口令均先做 NFKD 规范化，
+//! 盐为 "mnemonic" + 口令。与 MetaMask、Ledger 等钱包保持一致。
+//! 同时负责 12/15/18/21/24 词助记词的解析与校验和检查，
+//! 以及多语言词表（英文、简繁中文、日文、韩文、西班牙文、法文、意大利文、捷克文）
+//! 的选择和自动识别，并为输入错误的单词提供纠错建议。
+
+use bip39::{Language, Mnemonic};
+use pbkdf2::pbkdf2_hmac;
+use sha2::Sha512;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::error::WalletError;
+
+/// BIP39 规定的 PBKDF2 迭代次数
+const PBKDF2_ROUNDS: u32 = 2048;
+
+/// BIP39 种子长度（字节）
+pub const SEED_LEN: usize = 64;
+
+/// BIP39 允许的助记词单词数量
+pub const VALID_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+/// 默认熵强度（位），对应12个单词
+pub const DEFAULT_ENTROPY_BITS: u32 = 128;
+
+/// BIP39 词表保证前4个字母唯一
+const UNIQUE_PREFIX_LEN: usize = 4;
+
+/// 每个错误单词最多给出的纠错建议数量
+const MAX_SUGGESTIONS: usize = 3;
+
+/// 词表外的单词及其位置和纠错建议
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownWord {
+    /// 单词位置（从1开始）
+    pub position: usize,
+    pub word: String,
+    /// 按可能性排序的候选单词
+    pub suggestions: Vec<String>,
+}
+
+/// 助记词校验报告
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MnemonicReport {
+    pub word_count: usize,
+    pub word_count_valid: bool,
+    /// 识别出的词表语言；存在未知单词时为匹配单词最多的语言
+    pub language: Option<Language>,
+    pub unknown_words: Vec<UnknownWord>,
+    pub checksum_valid: bool,
+}
+
+impl MnemonicReport {
+    pub fn is_valid(&self) -> bool {
+        self.word_count_valid && self.unknown_words.is_empty() && self.checksum_valid
+    }
+}
+
+/// 规范化助记词：NFKD 规范化，并把空白规整为单个空格
+///
+/// 中文词表每个单词都是单个汉字，用户常常不加空格直接输入，
+/// 因此连续的汉字会被拆分为单独的单词。
+pub fn normalize_phrase(phrase: &str) -> String {
+    let normalized: String = phrase.nfkd().collect();
+    let mut words: Vec<String> = Vec::new();
+    for token in normalized.split_whitespace() {
+        if token.chars().count() > 1 && token.chars().all(is_cjk_ideograph) {
+            words.extend(token.chars().map(String::from));
+        } else {
+            words.push(token.to_string());
+        }
+    }
+    words.join(" ")
+}
+
+fn is_cjk_ideograph(c: char) -> bool {
+    matches!(c, '\u{4E00}'..='\u{9FFF}' | '\u{3400}'..='\u{4DBF}')
+}
+
+/// 将语言名称或语言代码解析为 BIP39 词表语言，空字符串表示英文
+pub fn parse_language(name: &str) -> Result<Language, WalletError> {
+    let language = match name.trim().to_lowercase().replace('-', "_").as_str() {
+        "" | "en" | "english" => Language::English,
+        "zh" | "zh_cn" | "zh_hans" | "chinese" | "chinese_simplified" | "simplified_chinese" => {
+            Language::SimplifiedChinese
+        }
+        "zh_tw" | "zh_hk" | "zh_hant" | "chinese_traditional" | "traditional_chinese" => {
+            Language::TraditionalChinese
+        }
+        "ja" | "japanese" => Language::Japanese,
+        "ko" | "korean" => Language::Korean,
+        "es" | "spanish" => Language::Spanish,
+        "fr" | "french" => Language::French,
+        "it" | "italian" => Language::Italian,
+        "cs" | "czech" => Language::Czech,
+        _ => {
+            return Err(WalletError::UnsupportedLanguage {
+                language: name.to_string(),
+            })
+        }
+    };
+    Ok(language)
+}
+
+/// 返回词表语言的代码，与 `parse_language` 可互相转换
+pub fn language_code(language: Language) -> &'static str {
+    match language {
+        Language::English => "english",
+        Language::SimplifiedChinese => "chinese_simplified",
+        Language::TraditionalChinese => "chinese_traditional",
+        Language::Japanese => "japanese",
+        Language::Korean => "korean",
+        Language::Spanish => "spanish",
+        Language::French => "french",
+        Language::Italian => "italian",
+        Language::Czech => "czech",
+    }
+}
+
+/// 自动识别已规范化助记词所用的词表语言
+///
+/// 简体和繁体中文词表共用大量汉字，所有单词都能在多个词表中找到时，
+/// 优先选择校验和有效的语言。
+pub fn detect_language(normalized: &str) -> Result<Language, WalletError> {
+    let mut candidates: Vec<Language> = Language::ALL.to_vec();
+    for (index, word) in normalized.split_whitespace().enumerate() {
+        let remaining: Vec<Language> = candidates
+            .iter()
+            .copied()
+            .filter(|language| language.find_word(word).is_some())
+            .collect();
+        if remaining.is_empty() {
+            return Err(WalletError::InvalidMnemonicWord {
+                position: index + 1,
+                word: word.to_string(),
+            });
+        }
+        candidates = remaining;
+    }
+
+    let language = candidates
+        .iter()
+        .copied()
+        .find(|language| Mnemonic::parse_in_normalized(*language, normalized).is_ok())
+        .unwrap_or(candidates[0]);
+    Ok(language)
+}
+
+/// 解析并校验助记词：单词数量、词表成员和校验和，语言自动识别
+pub fn parse_mnemonic(phrase: &str) -> Result<Mnemonic, WalletError> {
+    let normalized = normalize_phrase(phrase);
+    let words: Vec<&str> = normalized.split(' ').filter(|w| !w.is_empty()).collect();
+    if !VALID_WORD_COUNTS.contains(&words.len()) {
+        return Err(WalletError::InvalidWordCount { count: words.len() });
+    }
+
+    let language = detect_language(&normalized)?;
+    Mnemonic::parse_in_normalized(language, &normalized).map_err(|e| match e {
+        bip39::Error::UnknownWord(index) => WalletError::InvalidMnemonicWord {
+            position: index + 1,
+            word: words.get(index).copied().unwrap_or_default().to_string(),
+        },
+        bip39::Error::InvalidChecksum => WalletError::BadChecksum {
+            language: language_code(language).to_string(),
+        },
+        other => WalletError::InvalidMnemonic {
+            reason: other.to_string(),
+        },
+    })
+}
+
+/// 逐词校验助记词并生成结构化报告，供界面高亮和自动纠正错误单词
+pub fn validate_mnemonic(phrase: &str) -> MnemonicReport {
+    let normalized = normalize_phrase(phrase);
+    let words: Vec<&str> = normalized.split(' ').filter(|w| !w.is_empty()).collect();
+    let word_count_valid = VALID_WORD_COUNTS.contains(&words.len());
+
+    // 选择包含最多已知单词的语言，靠前的语言优先
+    let language = Language::ALL
+        .iter()
+        .copied()
+        .map(|language| {
+            let known = words
+                .iter()
+                .filter(|word| language.find_word(word).is_some())
+                .count();
+            (language, known)
+        })
+        .filter(|(_, known)| *known > 0)
+        .fold(
+            None,
+            |best: Option<(Language, usize)>, candidate| match best {
+                Some((_, known)) if known >= candidate.1 => best,
+                _ => Some(candidate),
+            },
+        )
+        .map(|(language, _)| language);
+
+    let unknown_words: Vec<UnknownWord> = match language {
+        Some(language) => words
+            .iter()
+            .enumerate()
+            .filter(|(_, word)| language.find_word(word).is_none())
+            .map(|(index, word)| UnknownWord {
+                position: index + 1,
+                word: word.to_string(),
+                suggestions: suggest_words(language, word),
+            })
+            .collect(),
+        None => words
+            .iter()
+            .enumerate()
+            .map(|(index, word)| UnknownWord {
+                position: index + 1,
+                word: word.to_string(),
+                suggestions: Vec::new(),
+            })
+            .collect(),
+    };
+
+    // 校验和只在单词数量正确且全部在词表中时才有意义；
+    // 简繁中文共用汉字，此时由校验和确定最终语言
+    let (language, checksum_valid) = if word_count_valid && unknown_words.is_empty() {
+        match detect_language(&normalized) {
+            Ok(detected) => (
+                Some(detected),
+                Mnemonic::parse_in_normalized(detected, &normalized).is_ok(),
+            ),
+            Err(_) => (language, false),
+        }
+    } else {
+        (language, false)
+    };
+
+    MnemonicReport {
+        word_count: words.len(),
+        word_count_valid,
+        language,
+        unknown_words,
+        checksum_valid,
+    }
+}
+
+/// 为词表外的单词查找候选：先按唯一的4字母前缀匹配，再按编辑距离排序
+fn suggest_words(language: Language, word: &str) -> Vec<String> {
+    let mut suggestions: Vec<String> = Vec::new();
+
+    let chars: Vec<char> = word.chars().collect();
+    if chars.len() >= UNIQUE_PREFIX_LEN {
+        let prefix: String = chars[..UNIQUE_PREFIX_LEN].iter().collect();
+        if let [unique] = language.words_by_prefix(&prefix) {
+            suggestions.push(unique.to_string());
+        }
+    }
+
+    // 单字词表（如中文）编辑距离没有区分度，不做模糊匹配
+    if chars.len() < 3 {
+        return suggestions;
+    }
+
+    let max_distance = if chars.len() <= 4 { 1 } else { 2 };
+    let mut candidates: Vec<(usize, &str)> = language
+        .word_list()
+        .iter()
+        .map(|candidate| (edit_distance(word, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    candidates.sort_by_key(|(distance, _)| *distance);
+
+    for (_, candidate) in candidates {
+        if suggestions.len() >= MAX_SUGGESTIONS {
+            break;
+        }
+        if !suggestions.iter().any(|s| s == candidate) {
+            suggestions.push(candidate.to_string());
+        }
+    }
+    suggestions
+}
+
+/// Levenshtein 编辑距离（按字符计算）
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// 使用系统密码学安全随机数（浏览器中为 `crypto.getRandomValues`）生成新助记词
+pub fn generate_mnemonic(word_count: usize, language: Language) -> Result<Mnemonic, WalletError> {
+    if !VALID_WORD_COUNTS.contains(&word_count) {
+        return Err(WalletError::InvalidWordCount { count: word_count });
+    }
+
+    // 每3个单词对应32位熵
+    let mut entropy = [0u8; 32];
+    let entropy = &mut entropy[..word_count / 3 * 4];
+    getrandom::getrandom(entropy).map_err(|e| WalletError::RandomnessUnavailable {
+        reason: format!("Failed to obtain secure random entropy: {}", e),
+    })?;
+
+    Mnemonic::from_entropy_in(language, entropy)
+        .map_err(|e| WalletError::crypto(format!("Failed to generate mnemonic: {}", e)))
+}
+
+/// 校验熵强度（128–256 位，32 的倍数），返回熵的字节数
+pub fn entropy_len(strength_bits: u32) -> Result<usize, WalletError> {
+    if !(128..=256).contains(&strength_bits) || !strength_bits.is_multiple_of(32) {
+        return Err(WalletError::invalid_argument(format!(
+            "Entropy strength must be 128, 160, 192, 224 or 256 bits, got {}",
+            strength_bits
+        )));
+    }
+    Ok(strength_bits as usize / 8)
+}
+
+/// 按 BIP39 规范从助记词和可选口令派生种子。
+///
+/// 助记词中的空白会被规整为单个空格，因此多余空格或换行不会改变结果。
+/// 本函数不校验单词和校验和，校验由调用方负责。
+pub fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> [u8; SEED_LEN] {
+    let sentence = normalize_phrase(mnemonic);
+    let salt: String = format!("mnemonic{}", passphrase).nfkd().collect();
+
+    let mut seed = [0u8; SEED_LEN];
+    pbkdf2_hmac::<Sha512>(
+        sentence.as_bytes(),
+        salt.as_bytes(),
+        PBKDF2_ROUNDS,
+        &mut seed,
+    );
+    seed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn seed_matches_bip39_vector() {
+        // BIP39 官方向量（口令 TREZOR）
+        assert_eq!(
+            hex::encode(mnemonic_to_seed(PHRASE, "TREZOR")),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+    }
+
+    #[test]
+    fn rejects_bad_checksum() {
+        let phrase = PHRASE.replace("about", "abandon");
+        assert_eq!(parse_mnemonic(&phrase).unwrap_err().code(), "BAD_CHECKSUM");
+        assert!(parse_mnemonic(PHRASE).is_ok());
+    }
+
+    #[test]
+    fn accepts_all_word_counts() {
+        let phrase = format!("{} art", "abandon ".repeat(23).trim_end());
+        assert_eq!(parse_mnemonic(&phrase).unwrap().word_count(), 24);
+        assert!(parse_mnemonic("abandon abandon").is_err());
+        assert_eq!(entropy_len(256).unwrap(), 32);
+        assert!(entropy_len(100).is_err());
+    }
+
+    #[test]
+    fn detects_wordlist_language() {
+        for language in Language::ALL.iter().copied() {
+            let phrase = Mnemonic::from_entropy_in(language, &[9u8; 32])
+                .unwrap()
+                .to_string();
+            assert_eq!(parse_mnemonic(&phrase).unwrap().language(), language);
+            assert_eq!(parse_language(language_code(language)).unwrap(), language);
+        }
+    }
+
+    #[test]
+    fn suggests_corrections_for_typos() {
+        let report = validate_mnemonic(&PHRASE.replace("about", "abuot"));
+        assert!(!report.is_valid());
+        assert_eq!(report.unknown_words.len(), 1);
+        assert_eq!(report.unknown_words[0].position, 12);
+        assert!(report.unknown_words[0]
+            .suggestions
+            .contains(&"about".to_string()));
+        assert!(validate_mnemonic(PHRASE).is_valid());
+    }
+
+    #[test]
+    fn generates_requested_word_counts() {
+        for count in VALID_WORD_COUNTS {
+            let generated = generate_mnemonic(count, Language::English).unwrap();
+            assert_eq!(generated.word_count(), count);
+        }
+        assert!(generate_mnemonic(13, Language::English).is_err());
+    }
+}
@@ -0,0 +1,329 @@
+//! 返回给 JS 的结果结构
+//!
+//! 字段统一按 camelCase 序列化，`TS_RESULT_TYPES` 中的 TypeScript 定义与下列结构体一一对应，
+//! 由 wasm-bindgen 写入生成的 `wasm_crypto.d.ts`，修改结构体时需同步修改。
+//! 助记词和私钥字段为 `Secret`，由 `serialize_with = "expose_secret"` 显式输出原值。
+
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+use crate::secret::Secret;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_RESULT_TYPES: &'static str = r#"
+export interface WalletResult {
+    mnemonic: string;
+    language: string;
+    path: string;
+    publicKey: string;
+    privateKey: string;
+    address: string;
+    chainType: string;
+    schemeVersion?: number;
+}
+
+export interface KeypairResult {
+    path: string;
+    publicKey: string;
+    privateKey: string;
+    address: string;
+    chainType: string;
+}
+
+export interface UnknownWord {
+    position: number;
+    word: string;
+    suggestions: string[];
+}
+
+export interface MnemonicReport {
+    valid: boolean;
+    wordCount: number;
+    wordCountValid: boolean;
+    language: string | null;
+    checksumValid: boolean;
+    unknownWords: UnknownWord[];
+}
+
+export interface AccountEntry {
+    index: number;
+    path: string;
+    address: string;
+    publicKey: string;
+}
+
+export interface SubstrateAccountResult {
+    scheme: string;
+    publicKey: string;
+    privateKey: string;
+    address: string;
+    ss58Prefix: number;
+}
+
+export interface CosmosAccountResult {
+    path: string;
+    publicKey: string;
+    privateKey: string;
+    address: string;
+    prefix: string;
+}
+
+export interface EthereumAddressCheck {
+    valid: boolean;
+    hasChecksum: boolean | null;
+    checksumAddress: string | null;
+    error: string | null;
+}
+
+export interface AddressValidation {
+    valid: boolean;
+    error: string | null;
+}
+
+export interface SignatureVerification {
+    success: boolean;
+    message: string;
+}
+
+export type RecoveryScheme = 'eip191' | 'digest' | 'substrate_ecdsa';
+
+export interface RecoveredSigner {
+    scheme: RecoveryScheme;
+    publicKey: string;
+    address: string;
+}
+
+export interface TypedDataHash {
+    domainSeparator: string;
+    structHash: string | null;
+    digest: string;
+}
+
+export interface TypedDataSignature extends TypedDataHash {
+    signature: string;
+}
+
+export interface SignedTransaction {
+    type: 0 | 1 | 2;
+    rawTransaction: string;
+    hash: string;
+}
+
+export type AbiValue = string | boolean | AbiValue[] | { [name: string]: AbiValue };
+
+export interface DecodedArgument {
+    name: string;
+    type: string;
+    value: AbiValue;
+}
+
+export interface DecodedCall {
+    name: string;
+    signature: string;
+    selector: string;
+    args: DecodedArgument[];
+}
+
+export type RevertKind = 'empty' | 'error' | 'panic' | 'custom' | 'unknown';
+
+export interface RevertReason {
+    kind: RevertKind;
+    message: string | null;
+    panicCode: string | null;
+    name: string | null;
+    signature: string | null;
+    selector: string | null;
+    args: DecodedArgument[];
+    data: string;
+}
+"#;
+
+/// 返回给 JS 的助记词和私钥在这里取出原值，`Secret` 本身不可序列化
+fn expose_secret<T: Serialize, S: Serializer>(
+    secret: &Secret<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    secret.expose().serialize(serializer)
+}
+
+/// 由助记词生成的钱包，`schemeVersion` 仅在由设备ID生成时出现
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletResult {
+    #[serde(serialize_with = "expose_secret")]
+    pub mnemonic: Secret<String>,
+    pub language: String,
+    pub path: String,
+    pub public_key: String,
+    #[serde(serialize_with = "expose_secret")]
+    pub private_key: Secret<String>,
+    pub address: String,
+    pub chain_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheme_version: Option<u32>,
+}
+
+/// 按路径派生的单个密钥对
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeypairResult {
+    pub path: String,
+    pub public_key: String,
+    #[serde(serialize_with = "expose_secret")]
+    pub private_key: Secret<String>,
+    pub address: String,
+    pub chain_type: String,
+}
+
+/// 助记词中不在词表里的单词，位置从1开始
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnknownWord {
+    pub position: u32,
+    pub word: String,
+    pub suggestions: Vec<String>,
+}
+
+/// 助记词校验报告
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MnemonicReport {
+    pub valid: bool,
+    pub word_count: u32,
+    pub word_count_valid: bool,
+    pub language: Option<String>,
+    pub checksum_valid: bool,
+    pub unknown_words: Vec<UnknownWord>,
+}
+
+/// 账户列表中的一项（不含私钥）
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountEntry {
+    pub index: u32,
+    pub path: String,
+    pub address: String,
+    pub public_key: String,
+}
+
+/// 按 SURI 派生的 Substrate 账户
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubstrateAccountResult {
+    pub scheme: String,
+    pub public_key: String,
+    #[serde(serialize_with = "expose_secret")]
+    pub private_key: Secret<String>,
+    pub address: String,
+    pub ss58_prefix: u16,
+}
+
+/// 按 bech32 前缀派生的 Cosmos SDK 账户
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CosmosAccountResult {
+    pub path: String,
+    pub public_key: String,
+    #[serde(serialize_with = "expose_secret")]
+    pub private_key: Secret<String>,
+    pub address: String,
+    pub prefix: String,
+}
+
+/// 以太坊地址校验结果，地址无效时 `hasChecksum` 和 `checksumAddress` 为 null
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthereumAddressCheck {
+    pub valid: bool,
+    pub has_checksum: Option<bool>,
+    pub checksum_address: Option<String>,
+    pub error: Option<String>,
+}
+
+/// 按链类型的地址校验结果
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressValidation {
+    pub valid: bool,
+    pub error: Option<String>,
+}
+
+/// 签名验证结果
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureVerification {
+    pub success: bool,
+    pub message: String,
+}
+
+/// 签名者恢复结果
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoveredSigner {
+    pub scheme: String,
+    pub public_key: String,
+    pub address: String,
+}
+
+/// EIP-712 的中间哈希和摘要；主类型为 `EIP712Domain` 时 `structHash` 为 null
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypedDataHash {
+    pub domain_separator: String,
+    pub struct_hash: Option<String>,
+    pub digest: String,
+}
+
+/// EIP-712 签名结果，附带被签名的中间哈希
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypedDataSignature {
+    #[serde(flatten)]
+    pub hash: TypedDataHash,
+    pub signature: String,
+}
+
+/// 签名后的以太坊交易，`rawTransaction` 可直接用于 `eth_sendRawTransaction`
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedTransaction {
+    #[serde(rename = "type")]
+    pub tx_type: u8,
+    pub raw_transaction: String,
+    pub hash: String,
+}
+
+/// ABI 解码出的参数，`value` 中整数为十进制字符串
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedArgument {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub value: Value,
+}
+
+/// 解码后的合约调用
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedCall {
+    pub name: String,
+    pub signature: String,
+    pub selector: String,
+    pub args: Vec<DecodedArgument>,
+}
+
+/// 回滚原因，不适用的字段为 null
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevertReason {
+    pub kind: String,
+    pub message: Option<String>,
+    pub panic_code: Option<String>,
+    pub name: Option<String>,
+    pub signature: Option<String>,
+    pub selector: Option<String>,
+    pub args: Vec<DecodedArgument>,
+    pub data: String,
+}
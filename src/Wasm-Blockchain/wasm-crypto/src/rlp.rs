@@ -0,0 +1,56 @@
+//! RLP 编码
+//!
+//! 只实现交易序列化需要的编码部分：字节串和列表，整数按去掉前导零的大端字节编码，
+//! 零编码为空字节串。
+
+use web3::types::U256;
+
+/// RLP 条目
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Item {
+    Bytes(Vec<u8>),
+    List(Vec<Item>),
+}
+
+impl Item {
+    /// 整数：去掉前导零的大端字节
+    pub fn uint(value: U256) -> Self {
+        let mut bytes = [0u8; 32];
+        value.to_big_endian(&mut bytes);
+        let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+        Item::Bytes(bytes[start..].to_vec())
+    }
+
+    pub fn bytes(value: &[u8]) -> Self {
+        Item::Bytes(value.to_vec())
+    }
+}
+
+pub fn encode(item: &Item) -> Vec<u8> {
+    match item {
+        Item::Bytes(bytes) if bytes.len() == 1 && bytes[0] < 0x80 => bytes.clone(),
+        Item::Bytes(bytes) => {
+            let mut encoded = length_prefix(0x80, bytes.len());
+            encoded.extend_from_slice(bytes);
+            encoded
+        }
+        Item::List(items) => {
+            let payload: Vec<u8> = items.iter().flat_map(encode).collect();
+            let mut encoded = length_prefix(0xc0, payload.len());
+            encoded.extend_from_slice(&payload);
+            encoded
+        }
+    }
+}
+
+/// 长度不超过55字节时前缀为 offset + 长度，否则为 offset + 55 + 长度的字节数，后接长度本身
+fn length_prefix(offset: u8, len: usize) -> Vec<u8> {
+    if len <= 55 {
+        return vec![offset + len as u8];
+    }
+    let len_bytes = len.to_be_bytes();
+    let start = len_bytes.iter().position(|b| *b != 0).unwrap_or(0);
+    let mut prefix = vec![offset + 55 + (len_bytes.len() - start) as u8];
+    prefix.extend_from_slice(&len_bytes[start..]);
+    prefix
+}
@@ -0,0 +1,45 @@
+//! 敏感数据包装
+//!
+//! 助记词、种子和私钥在 crate 内部一律以 `Secret` 传递：不实现 `Display` 和 `Serialize`，`Debug` 只输出
+//! `[REDACTED]`，因此不会被日志宏、`{:?}` 或 JSON 序列化意外输出。返回给调用方时须显式调用 `expose`。
+
+use std::fmt;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Secret<T>(T);
+
+impl<T> Secret<T> {
+    pub fn new(value: T) -> Self {
+        Secret(value)
+    }
+
+    /// 取出原值，仅用于计算或返回给调用方，不要用于日志
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Secret(value)
+    }
+}
+
+impl<T> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_is_redacted() {
+        let secret = Secret::new(String::from("abandon about"));
+        assert_eq!(format!("{:?}", secret), "[REDACTED]");
+        assert_eq!(format!("{:?}", Some(&secret)), "Some([REDACTED])");
+        assert_eq!(secret.expose(), "abandon about");
+    }
+}
@@ -0,0 +1,56 @@
+//! SLIP-10 ed25519 分层确定性密钥派生
+//!
+//! ed25519 只支持硬化派生，路径格式与 BIP32 相同，例如 `m/44'/501'/0'/0'`。
+
+use crate::bip32::{hmac_sha512, ChildNumber, DerivationPath};
+
+/// SLIP-10 ed25519 主密钥的 HMAC 密钥
+const ED25519_HMAC_KEY: &[u8] = b"ed25519 seed";
+
+/// SLIP-10 ed25519 扩展私钥
+#[derive(Clone)]
+pub struct Ed25519ExtendedKey {
+    secret: [u8; 32],
+    chain_code: [u8; 32],
+}
+
+impl Ed25519ExtendedKey {
+    /// 从种子生成主密钥：I = HMAC-SHA512("ed25519 seed", seed)
+    pub fn master(seed: &[u8]) -> Result<Self, String> {
+        if !(16..=64).contains(&seed.len()) {
+            return Err(format!(
+                "Seed must be between 16 and 64 bytes, got {}",
+                seed.len()
+            ));
+        }
+
+        let (secret, chain_code) = hmac_sha512(ED25519_HMAC_KEY, &[seed]);
+        Ok(Ed25519ExtendedKey { secret, chain_code })
+    }
+
+    /// 派生一级硬化子密钥，普通索引返回错误
+    pub fn derive_child(&self, child: ChildNumber) -> Result<Self, String> {
+        if !child.is_hardened() {
+            return Err(format!(
+                "ed25519 supports only hardened derivation, got index {}",
+                child
+            ));
+        }
+
+        let index = child.to_index().to_be_bytes();
+        let (secret, chain_code) = hmac_sha512(&self.chain_code, &[&[0u8], &self.secret, &index]);
+        Ok(Ed25519ExtendedKey { secret, chain_code })
+    }
+
+    /// 沿路径逐级派生
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, String> {
+        path.children()
+            .iter()
+            .try_fold(self.clone(), |key, child| key.derive_child(*child))
+    }
+
+    /// 32字节 ed25519 私钥种子
+    pub fn secret_bytes(&self) -> [u8; 32] {
+        self.secret
+    }
+}
@@ -0,0 +1,177 @@
+//! Solana 账户
+//!
+//! 按 SLIP-10 在 `m/44'/501'/n'/0'` 派生 ed25519 密钥，地址为公钥的 base58 编码，
+//! 私钥按 Phantom 和 solana-cli 使用的64字节密钥对（私钥种子 || 公钥）导出。
+
+use std::str::FromStr;
+
+use sp_core::{ed25519, Pair};
+
+use crate::bip32::DerivationPath;
+use crate::chain::{ChainAccount, ChainAdapter};
+use crate::error::WalletError;
+use crate::secret::Secret;
+use crate::slip10::Ed25519ExtendedKey;
+
+/// Solana 的 SLIP-44 币种编号
+pub const COIN_TYPE: u32 = 501;
+
+/// Solana 密钥对
+pub struct SolanaKeypair {
+    pub public_key: [u8; 32],
+    /// 私钥种子 || 公钥
+    pub keypair: [u8; 64],
+}
+
+impl SolanaKeypair {
+    pub fn from_secret(secret: &[u8; 32]) -> Self {
+        let public_key = ed25519::Pair::from_seed(secret).public().0;
+        let mut keypair = [0u8; 64];
+        keypair[..32].copy_from_slice(secret);
+        keypair[32..].copy_from_slice(&public_key);
+        SolanaKeypair {
+            public_key,
+            keypair,
+        }
+    }
+
+    /// base58 编码的公钥即为地址
+    pub fn address(&self) -> String {
+        bs58::encode(self.public_key).into_string()
+    }
+
+    /// base58 编码的64字节密钥对，可直接导入 Phantom
+    pub fn keypair_base58(&self) -> String {
+        bs58::encode(self.keypair).into_string()
+    }
+}
+
+/// Solana 适配器：公钥、地址和64字节密钥对均为 base58，签名为 ed25519
+pub struct SolanaAdapter;
+
+pub fn adapter(chain_type: &str) -> Result<Option<Box<dyn ChainAdapter>>, WalletError> {
+    match chain_type.to_lowercase().as_str() {
+        "solana" | "sol" => Ok(Some(Box::new(SolanaAdapter))),
+        _ => Ok(None),
+    }
+}
+
+impl ChainAdapter for SolanaAdapter {
+    fn name(&self) -> &'static str {
+        "solana"
+    }
+
+    /// 账户索引位于第三级：`m/44'/501'/index'/0'`
+    fn account_path(&self, index: u32) -> String {
+        format!("m/44'/{}'/{}'/0'", COIN_TYPE, index)
+    }
+
+    fn derive_account(&self, seed: &[u8], path: &str) -> Result<ChainAccount, WalletError> {
+        let parsed =
+            DerivationPath::from_str(path).map_err(|e| WalletError::invalid_path(path, e))?;
+        let secret = Ed25519ExtendedKey::master(seed)
+            .map_err(WalletError::derivation)?
+            .derive_path(&parsed)
+            .map_err(|e| WalletError::invalid_path(path, e))?
+            .secret_bytes();
+
+        let keypair = SolanaKeypair::from_secret(&secret);
+        Ok(ChainAccount {
+            public_key: keypair.address(),
+            private_key: Secret::new(keypair.keypair_base58()),
+            address: keypair.address(),
+        })
+    }
+
+    fn format_address(&self, public_key: &[u8]) -> Result<String, WalletError> {
+        if public_key.len() != 32 {
+            return Err(WalletError::invalid_key(format!(
+                "ed25519 public key must be 32 bytes, got {}",
+                public_key.len()
+            )));
+        }
+        Ok(bs58::encode(public_key).into_string())
+    }
+
+    /// 任意32字节均为合法地址（PDA 不在曲线上）
+    fn validate_address(&self, address: &str) -> Result<(), WalletError> {
+        decode_public_key(address)
+            .map(|_| ())
+            .map_err(WalletError::invalid_address)
+    }
+
+    /// 私钥接受64字节密钥对或32字节私钥种子（base58）
+    fn sign(&self, private_key: &str, message: &[u8]) -> Result<Vec<u8>, WalletError> {
+        let bytes = bs58::decode(private_key.trim())
+            .into_vec()
+            .map_err(|e| WalletError::invalid_key(format!("Invalid base58 private key: {}", e)))?;
+        if bytes.len() != 64 && bytes.len() != 32 {
+            return Err(WalletError::invalid_key(format!(
+                "Solana private key must be 64 or 32 bytes, got {}",
+                bytes.len()
+            )));
+        }
+
+        let pair = ed25519::Pair::from_seed_slice(&bytes[..32]).map_err(|e| {
+            WalletError::invalid_key(format!("Invalid ed25519 private key: {:?}", e))
+        })?;
+        if bytes.len() == 64 && pair.public().0[..] != bytes[32..] {
+            return Err(WalletError::invalid_key(
+                "Keypair public key does not match its secret",
+            ));
+        }
+        Ok(pair.sign(message).0.to_vec())
+    }
+
+    fn verify(
+        &self,
+        public_key: &str,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, WalletError> {
+        let public = ed25519::Public::from_raw(
+            decode_public_key(public_key).map_err(WalletError::invalid_key)?,
+        );
+        let signature = <[u8; 64]>::try_from(signature).map_err(|_| {
+            WalletError::invalid_signature(format!(
+                "ed25519 signature must be 64 bytes, got {}",
+                signature.len()
+            ))
+        })?;
+        Ok(ed25519::Pair::verify(
+            &ed25519::Signature::from_raw(signature),
+            message,
+            &public,
+        ))
+    }
+}
+
+fn decode_public_key(value: &str) -> Result<[u8; 32], String> {
+    let bytes = bs58::decode(value.trim())
+        .into_vec()
+        .map_err(|e| format!("Invalid base58 address: {}", e))?;
+    <[u8; 32]>::try_from(bytes.as_slice())
+        .map_err(|_| format!("Solana address must be 32 bytes, got {}", bytes.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derives_phantom_address() {
+        // Phantom/Solflare 对同一助记词的默认地址
+        let seed = crate::mnemonic::mnemonic_to_seed(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "",
+        );
+        let account = SolanaAdapter
+            .derive_account(&seed, &SolanaAdapter.default_path())
+            .unwrap();
+        assert_eq!(SolanaAdapter.default_path(), "m/44'/501'/0'/0'");
+        assert_eq!(
+            account.address,
+            "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk"
+        );
+    }
+}
@@ -0,0 +1,559 @@
+//! Substrate（Polkadot/Kusama）账户
+//!
+//! 与 polkadot.js 的 `@polkadot/keyring` 保持一致：
+//! mini secret = PBKDF2-HMAC-SHA512(助记词熵, "mnemonic" + 口令, 2048)[..32]，
+//! 由 mini secret 展开 sr25519 密钥对，地址使用 SS58 编码。
+//! 另外支持 `phrase//hard/soft///password` 形式的 SURI 以及 ed25519、ecdsa 方案。
+
+use pbkdf2::pbkdf2_hmac;
+use rand_core::{CryptoRng, RngCore};
+use schnorrkel::derive::{ChainCode, Derivation};
+use schnorrkel::{ExpansionMode, MiniSecretKey};
+use sha2::Sha512;
+use sp_core::{
+    ecdsa, ed25519,
+    hashing::{blake2_256, blake2_512},
+    sr25519, DeriveJunction, Pair,
+};
+
+use crate::chain::{decode_hex, ChainAccount, ChainAdapter};
+use crate::error::WalletError;
+use crate::mnemonic;
+use crate::secret::Secret;
+
+/// SS58 网络前缀
+pub const POLKADOT_PREFIX: u16 = 0;
+pub const KUSAMA_PREFIX: u16 = 2;
+pub const GENERIC_SUBSTRATE_PREFIX: u16 = 42;
+
+/// SS58 支持的最大网络前缀（14位）
+pub const MAX_SS58_PREFIX: u16 = 16_383;
+
+/// SS58 校验和的哈希前缀
+const SS58_CHECKSUM_PREFIX: &[u8] = b"SS58PRE";
+
+/// SS58 校验和长度（32字节公钥）
+const SS58_CHECKSUM_LEN: usize = 2;
+
+/// BIP39 规定的 PBKDF2 迭代次数
+const PBKDF2_ROUNDS: u32 = 2048;
+
+/// SURI 省略助记词时使用的开发助记词，与 sp-core 的 `DEV_PHRASE` 相同
+pub const DEV_PHRASE: &str =
+    "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+
+/// Substrate 签名方案
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubstrateScheme {
+    Sr25519,
+    Ed25519,
+    Ecdsa,
+}
+
+impl SubstrateScheme {
+    /// 解析方案名称，空字符串默认为 sr25519
+    pub fn parse(name: &str) -> Result<Self, WalletError> {
+        match name.trim().to_lowercase().as_str() {
+            "" | "sr25519" => Ok(SubstrateScheme::Sr25519),
+            "ed25519" => Ok(SubstrateScheme::Ed25519),
+            "ecdsa" | "secp256k1" => Ok(SubstrateScheme::Ecdsa),
+            _ => Err(WalletError::invalid_argument(format!(
+                "Unsupported Substrate scheme: '{}'",
+                name
+            ))),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            SubstrateScheme::Sr25519 => "sr25519",
+            SubstrateScheme::Ed25519 => "ed25519",
+            SubstrateScheme::Ecdsa => "ecdsa",
+        }
+    }
+}
+
+/// 拆分后的 SURI：`<phrase 或 0x种子><path>///<password>`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SecretUri<'a> {
+    pub phrase: &'a str,
+    pub path: &'a str,
+    pub password: Option<&'a str>,
+}
+
+/// SURI 派生出的 Substrate 密钥
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubstrateKeypair {
+    pub public_key: Vec<u8>,
+    pub secret: Secret<Vec<u8>>,
+    /// 链上账户ID；ecdsa 为压缩公钥的 blake2_256 哈希
+    pub account_id: [u8; 32],
+}
+
+/// 返回 Substrate 系链的 SS58 网络前缀，非 Substrate 链返回 None
+pub fn ss58_prefix(chain_type: &str) -> Option<u16> {
+    match chain_type.to_lowercase().as_str() {
+        "polkadot" => Some(POLKADOT_PREFIX),
+        "kusama" => Some(KUSAMA_PREFIX),
+        "substrate" => Some(GENERIC_SUBSTRATE_PREFIX),
+        _ => None,
+    }
+}
+
+/// 按 sp-core `from_string_with_seed` 的规则拆分 SURI
+///
+/// 口令从第一个 `///` 开始，助记词在第一个 `/` 之前结束，
+/// 省略助记词时使用开发助记词。
+pub fn parse_suri(suri: &str) -> Result<SecretUri<'_>, String> {
+    let (rest, password) = match suri.find("///") {
+        Some(index) => (&suri[..index], Some(&suri[index + 3..])),
+        None => (suri, None),
+    };
+
+    let split = rest.find('/').unwrap_or(rest.len());
+    let phrase = rest[..split].trim();
+    let path = &rest[split..];
+
+    Ok(SecretUri {
+        phrase: if phrase.is_empty() {
+            DEV_PHRASE
+        } else {
+            phrase
+        },
+        path,
+        password,
+    })
+}
+
+/// 从 SURI 派生指定方案的密钥
+///
+/// sp-core 的 `Pair::from_string_with_seed` 依赖其 `std` 特性，而 wasm 构建中未启用，
+/// 因此这里按相同规则解析 SURI，再通过 sp-core 的 `Pair::derive` 完成派生。
+pub fn keypair_from_suri(
+    suri: &str,
+    scheme: SubstrateScheme,
+) -> Result<SubstrateKeypair, WalletError> {
+    let uri = parse_suri(suri).map_err(WalletError::invalid_argument)?;
+    let junctions =
+        parse_junctions(uri.path).map_err(|e| WalletError::invalid_path(uri.path, e))?;
+
+    // 助记词按 substrate-bip39 派生种子；0x 开头视为32字节原始种子，此时忽略口令
+    let seed = match uri.phrase.strip_prefix("0x") {
+        Some(hex_seed) => {
+            let bytes = hex::decode(hex_seed)
+                .map_err(|e| WalletError::invalid_key(format!("Invalid hex seed: {}", e)))?;
+            <[u8; 32]>::try_from(bytes.as_slice()).map_err(|_| {
+                WalletError::invalid_key(format!("Hex seed must be 32 bytes, got {}", bytes.len()))
+            })?
+        }
+        None => {
+            let parsed = mnemonic::parse_mnemonic(uri.phrase)?;
+            let (entropy, entropy_len) = parsed.to_entropy_array();
+            mini_secret_from_entropy(&entropy[..entropy_len], uri.password.unwrap_or(""))
+                .map_err(WalletError::derivation)?
+        }
+    };
+
+    let keypair = match scheme {
+        SubstrateScheme::Sr25519 => {
+            let pair = sr25519_pair(&seed, &junctions).map_err(WalletError::derivation)?;
+            SubstrateKeypair {
+                public_key: pair.public().0.to_vec(),
+                secret: Secret::new(pair.to_raw_vec()),
+                account_id: pair.public().0,
+            }
+        }
+        SubstrateScheme::Ed25519 => {
+            let (pair, _) = ed25519::Pair::from_seed(&seed)
+                .derive(junctions.into_iter(), None)
+                .map_err(|_| {
+                    WalletError::invalid_path(
+                        uri.path,
+                        "ed25519 supports only hard derivation (//)",
+                    )
+                })?;
+            SubstrateKeypair {
+                public_key: pair.public().0.to_vec(),
+                secret: Secret::new(pair.to_raw_vec()),
+                account_id: pair.public().0,
+            }
+        }
+        SubstrateScheme::Ecdsa => {
+            let (pair, _) = ecdsa::Pair::from_seed(&seed)
+                .derive(junctions.into_iter(), None)
+                .map_err(|_| {
+                    WalletError::invalid_path(uri.path, "ecdsa supports only hard derivation (//)")
+                })?;
+            let public = pair.public();
+            let public_bytes: &[u8] = public.as_ref();
+            SubstrateKeypair {
+                public_key: public_bytes.to_vec(),
+                secret: Secret::new(pair.to_raw_vec()),
+                account_id: blake2_256(public_bytes),
+            }
+        }
+    };
+    Ok(keypair)
+}
+
+/// 按 substrate-bip39 规则从助记词熵派生 mini secret
+///
+/// 与 substrate-bip39 和 polkadot.js 的 `mnemonicToMiniSecret` 一致，口令按原始 UTF-8 字节加盐，不做 NFKD 规范化。
+pub fn mini_secret_from_entropy(entropy: &[u8], password: &str) -> Result<[u8; 32], String> {
+    if !(16..=32).contains(&entropy.len()) || !entropy.len().is_multiple_of(4) {
+        return Err(format!(
+            "Invalid mnemonic entropy length: {}",
+            entropy.len()
+        ));
+    }
+
+    let salt = format!("mnemonic{}", password);
+    let mut seed = [0u8; 64];
+    pbkdf2_hmac::<Sha512>(entropy, salt.as_bytes(), PBKDF2_ROUNDS, &mut seed);
+
+    let mut mini_secret = [0u8; 32];
+    mini_secret.copy_from_slice(&seed[..32]);
+    Ok(mini_secret)
+}
+
+/// 解析 `//hard/soft` 形式的派生路径，`//` 为硬派生，`/` 为软派生
+///
+/// 数字段按 u64 编码，其余按字符串编码，与 polkadot.js 和 sp-core 一致。
+pub fn parse_junctions(path: &str) -> Result<Vec<DeriveJunction>, String> {
+    let mut junctions = Vec::new();
+    let mut rest = path.trim();
+    while !rest.is_empty() {
+        let (hard, body) = match rest.strip_prefix("//") {
+            Some(body) => (true, body),
+            None => match rest.strip_prefix('/') {
+                Some(body) => (false, body),
+                None => return Err(format!("Invalid derivation path: '{}'", path)),
+            },
+        };
+
+        let end = body.find('/').unwrap_or(body.len());
+        let code = &body[..end];
+        if code.is_empty() {
+            return Err(format!("Empty junction in derivation path: '{}'", path));
+        }
+
+        let junction = DeriveJunction::from(code);
+        junctions.push(if hard { junction.harden() } else { junction });
+        rest = &body[end..];
+    }
+    Ok(junctions)
+}
+
+/// 从 mini secret 生成 sr25519 密钥对，并依次应用派生路径
+///
+/// 硬派生与 sp-core 相同。软派生的公钥与 sp-core 相同，但 sp-core 用系统随机数生成子私钥的 nonce 部分，
+/// 这里改由父私钥确定性派生，使同一路径每次导出相同的私钥。
+pub fn sr25519_pair(
+    mini_secret: &[u8; 32],
+    junctions: &[DeriveJunction],
+) -> Result<sr25519::Pair, String> {
+    let mut secret = MiniSecretKey::from_bytes(mini_secret)
+        .map_err(|e| format!("Invalid sr25519 mini secret: {}", e))?
+        .expand(ExpansionMode::Ed25519);
+    for junction in junctions {
+        secret = match junction {
+            DeriveJunction::Soft(cc) => {
+                secret
+                    .derived_key_simple_rng(ChainCode(*cc), [], NoExternalRandomness)
+                    .0
+            }
+            DeriveJunction::Hard(cc) => secret
+                .hard_derive_mini_secret_key(Some(ChainCode(*cc)), b"")
+                .0
+                .expand(ExpansionMode::Ed25519),
+        };
+    }
+
+    sr25519::Pair::from_seed_slice(&secret.to_bytes())
+        .map_err(|e| format!("Failed to derive sr25519 key: {:?}", e))
+}
+
+/// 不提供外部随机数的 RNG，只输出零
+///
+/// schnorrkel 软派生时以父私钥和 nonce 为种子生成子 nonce，再混入 RNG 的输出；
+/// 混入固定值后子 nonce 只由父私钥决定，而父私钥本身是保密的。
+struct NoExternalRandomness;
+
+impl RngCore for NoExternalRandomness {
+    fn next_u32(&mut self) -> u32 {
+        0
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        dest.fill(0);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        dest.fill(0);
+        Ok(())
+    }
+}
+
+impl CryptoRng for NoExternalRandomness {}
+
+/// 将 32 字节公钥编码为 SS58 地址
+pub fn ss58_encode(public_key: &[u8; 32], prefix: u16) -> String {
+    let mut data = encode_prefix(prefix);
+    data.extend_from_slice(public_key);
+
+    let checksum = ss58_checksum(&data);
+    data.extend_from_slice(&checksum[..SS58_CHECKSUM_LEN]);
+    bs58::encode(data).into_string()
+}
+
+/// 解码 SS58 地址，返回 (网络前缀, 32字节账户ID)
+pub fn ss58_decode(address: &str) -> Result<(u16, [u8; 32]), String> {
+    let data = bs58::decode(address.trim())
+        .into_vec()
+        .map_err(|e| format!("Invalid base58 address: {}", e))?;
+
+    let (prefix, prefix_len) = match data.first() {
+        Some(&first) if first < 64 => (u16::from(first), 1),
+        Some(&first) if first < 128 && data.len() > 1 => {
+            let second = data[1];
+            let lower = (u16::from(first & 0b0011_1111) << 2) | u16::from(second >> 6);
+            let upper = u16::from(second & 0b0011_1111);
+            (lower | (upper << 8), 2)
+        }
+        _ => return Err("Invalid SS58 address prefix".to_string()),
+    };
+
+    if data.len() != prefix_len + 32 + SS58_CHECKSUM_LEN {
+        return Err(format!("Invalid SS58 address length: {} bytes", data.len()));
+    }
+    let (body, checksum) = data.split_at(prefix_len + 32);
+    if checksum != &ss58_checksum(body)[..SS58_CHECKSUM_LEN] {
+        return Err("Invalid SS58 checksum".to_string());
+    }
+
+    let mut account_id = [0u8; 32];
+    account_id.copy_from_slice(&body[prefix_len..]);
+    Ok((prefix, account_id))
+}
+
+/// 前缀 0–63 编码为单字节，64–16383 编码为双字节
+fn encode_prefix(prefix: u16) -> Vec<u8> {
+    if prefix < 64 {
+        vec![prefix as u8]
+    } else {
+        let first = ((prefix & 0b0000_0000_1111_1100) >> 2) as u8 | 0b0100_0000;
+        let second = ((prefix >> 8) as u8) | (((prefix & 0b0000_0000_0000_0011) as u8) << 6);
+        vec![first, second]
+    }
+}
+
+fn ss58_checksum(data: &[u8]) -> [u8; 64] {
+    let mut preimage = Vec::with_capacity(SS58_CHECKSUM_PREFIX.len() + data.len());
+    preimage.extend_from_slice(SS58_CHECKSUM_PREFIX);
+    preimage.extend_from_slice(data);
+    blake2_512(&preimage)
+}
+
+/// Substrate sr25519 适配器：种子为 mini secret，路径为 `//hard/soft`，密钥为带0x前缀的十六进制
+pub struct SubstrateAdapter {
+    prefix: u16,
+}
+
+pub fn adapter(chain_type: &str) -> Result<Option<Box<dyn ChainAdapter>>, WalletError> {
+    Ok(ss58_prefix(chain_type)
+        .map(|prefix| Box::new(SubstrateAdapter { prefix }) as Box<dyn ChainAdapter>))
+}
+
+impl ChainAdapter for SubstrateAdapter {
+    fn name(&self) -> &'static str {
+        "substrate"
+    }
+
+    fn account_path(&self, index: u32) -> String {
+        format!("//{}", index)
+    }
+
+    /// 默认使用助记词根密钥（空路径），与 polkadot.js 直接导入助记词的结果一致
+    fn default_path(&self) -> String {
+        String::new()
+    }
+
+    fn seed(&self, mnemonic_words: &str, passphrase: &str) -> Result<Secret<Vec<u8>>, WalletError> {
+        let parsed = mnemonic::parse_mnemonic(mnemonic_words)?;
+        let (entropy, entropy_len) = parsed.to_entropy_array();
+        let mini_secret = mini_secret_from_entropy(&entropy[..entropy_len], passphrase)
+            .map_err(WalletError::derivation)?;
+        Ok(Secret::new(mini_secret.to_vec()))
+    }
+
+    fn derive_account(&self, seed: &[u8], path: &str) -> Result<ChainAccount, WalletError> {
+        let mini_secret = <[u8; 32]>::try_from(seed).map_err(|_| {
+            WalletError::derivation(format!("Mini secret must be 32 bytes, got {}", seed.len()))
+        })?;
+        let junctions = parse_junctions(path).map_err(|e| WalletError::invalid_path(path, e))?;
+        let pair = sr25519_pair(&mini_secret, &junctions).map_err(WalletError::derivation)?;
+        let public = pair.public().0;
+        Ok(ChainAccount {
+            public_key: format!("0x{}", hex::encode(public)),
+            private_key: Secret::new(format!("0x{}", hex::encode(pair.to_raw_vec()))),
+            address: ss58_encode(&public, self.prefix),
+        })
+    }
+
+    fn format_address(&self, public_key: &[u8]) -> Result<String, WalletError> {
+        let public = <[u8; 32]>::try_from(public_key).map_err(|_| {
+            WalletError::invalid_key(format!(
+                "sr25519 public key must be 32 bytes, got {}",
+                public_key.len()
+            ))
+        })?;
+        Ok(ss58_encode(&public, self.prefix))
+    }
+
+    fn validate_address(&self, address: &str) -> Result<(), WalletError> {
+        let (prefix, _) = ss58_decode(address).map_err(WalletError::invalid_address)?;
+        if prefix != self.prefix {
+            return Err(WalletError::invalid_address(format!(
+                "SS58 prefix mismatch: expected {}, got {}",
+                self.prefix, prefix
+            )));
+        }
+        Ok(())
+    }
+
+    /// 私钥接受64字节 sr25519 私钥或32字节 mini secret
+    fn sign(&self, private_key: &str, message: &[u8]) -> Result<Vec<u8>, WalletError> {
+        let secret = decode_hex(private_key)
+            .map_err(|e| WalletError::invalid_key(format!("Invalid hex private key: {}", e)))?;
+        let pair = sr25519::Pair::from_seed_slice(&secret).map_err(|e| {
+            WalletError::invalid_key(format!("Invalid sr25519 private key: {:?}", e))
+        })?;
+        Ok(pair.sign(message).0.to_vec())
+    }
+
+    fn verify(
+        &self,
+        public_key: &str,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, WalletError> {
+        let public = decode_hex(public_key)
+            .map_err(|e| WalletError::invalid_key(format!("Invalid hex public key: {}", e)))?;
+        let public = <[u8; 32]>::try_from(public.as_slice())
+            .map_err(|_| WalletError::invalid_key("sr25519 public key must be 32 bytes"))?;
+        let signature = <[u8; 64]>::try_from(signature).map_err(|_| {
+            WalletError::invalid_signature(format!(
+                "sr25519 signature must be 64 bytes, got {}",
+                signature.len()
+            ))
+        })?;
+        Ok(sr25519::Pair::verify(
+            &sr25519::Signature::from_raw(signature),
+            message,
+            &sr25519::Public::from_raw(public),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derives_alice() {
+        // subkey inspect //Alice 的输出
+        let sr25519 = keypair_from_suri("//Alice", SubstrateScheme::Sr25519).unwrap();
+        assert_eq!(
+            ss58_encode(&sr25519.account_id, GENERIC_SUBSTRATE_PREFIX),
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+        );
+        assert_eq!(
+            ss58_encode(&sr25519.account_id, POLKADOT_PREFIX),
+            "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"
+        );
+        let ed25519 = keypair_from_suri("//Alice", SubstrateScheme::Ed25519).unwrap();
+        assert_eq!(
+            ss58_encode(&ed25519.account_id, GENERIC_SUBSTRATE_PREFIX),
+            "5FA9nQDVg267DEd8m1ZypXLBnvN7SFxYwV7ndqSYGiN9TTpu"
+        );
+        let ecdsa = keypair_from_suri("//Alice", SubstrateScheme::Ecdsa).unwrap();
+        assert_eq!(
+            hex::encode(&ecdsa.public_key),
+            "020a1091341fe5664bfa1782d5e04779689068c916b04cb365ec3153755684d9a1"
+        );
+        assert_eq!(
+            ss58_encode(&ecdsa.account_id, GENERIC_SUBSTRATE_PREFIX),
+            "5C7C2Z5sWbytvHpuLTvzKunnnRwQxft1jiqrLD5rhucQ5S9X"
+        );
+    }
+
+    #[test]
+    fn parses_secret_uri() {
+        let uri = parse_suri("//polkadot//0/1///pwd").unwrap();
+        assert_eq!(uri.phrase, DEV_PHRASE);
+        assert_eq!(uri.path, "//polkadot//0/1");
+        assert_eq!(uri.password, Some("pwd"));
+        assert_eq!(parse_suri("//x///").unwrap().password, Some(""));
+    }
+
+    #[test]
+    fn matches_sp_core_secret_uri_vectors() {
+        // sp-core Pair::from_string_with_seed（subkey inspect 同一实现）的输出
+        const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let public = |path: &str, scheme| {
+            let keypair = keypair_from_suri(&format!("{}{}", PHRASE, path), scheme).unwrap();
+            hex::encode(keypair.public_key)
+        };
+
+        assert_eq!(
+            public("//polkadot//0/1///pwd", SubstrateScheme::Sr25519),
+            "726845587327753527e4b047d6964ca5db9adae883a1b6a294f7f1cb6e448667"
+        );
+        assert_eq!(
+            public("//polkadot//0/1", SubstrateScheme::Sr25519),
+            "245211db8e3bb1ec0ae73433a6518e8b3dfaa87fc526c70cf42d55100f79f70d"
+        );
+        assert_eq!(
+            public("//polkadot//0//1///pwd", SubstrateScheme::Sr25519),
+            "d8dc40e6a7b6590d6d0e448c0f68aaf14a0c49a452626d957d0da9518352de56"
+        );
+        assert_eq!(
+            public("//polkadot//0//1///pwd", SubstrateScheme::Ed25519),
+            "2424ef5e3a0e621dbd35e7fa598cbbe0a67a09d68b201b5761cee04c721c3aa0"
+        );
+        assert_eq!(
+            public("//polkadot//0//1///pwd", SubstrateScheme::Ecdsa),
+            "026efcf21183a8fc515f5c275cde7d3b7a68626a2b20cc75459717c33f553c50f6"
+        );
+        assert_eq!(
+            public("///pwd", SubstrateScheme::Sr25519),
+            "4292e337a661436de60ea196cd8a9eea540bec2eb6334ef0597b8694e5565213"
+        );
+        assert_eq!(
+            public("///pwd", SubstrateScheme::Ed25519),
+            "f1897bfa65b2035ac80db6e5cef92d0e0a5fc3af43198a8b828fecdee5196aae"
+        );
+        assert_eq!(
+            public("///pwd", SubstrateScheme::Ecdsa),
+            "030101b38a44291e82a304e7be8acc36fe7422eda306700d8c81b7a2eda8c738e1"
+        );
+
+        // 与 sp-core 一致，ed25519 和 ecdsa 拒绝软派生
+        for scheme in [SubstrateScheme::Ed25519, SubstrateScheme::Ecdsa] {
+            let suri = format!("{}//polkadot//0/1///pwd", PHRASE);
+            assert!(keypair_from_suri(&suri, scheme).is_err());
+        }
+    }
+
+    #[test]
+    fn soft_derivation_is_deterministic() {
+        let first = keypair_from_suri("//Alice/soft", SubstrateScheme::Sr25519).unwrap();
+        let second = keypair_from_suri("//Alice/soft", SubstrateScheme::Sr25519).unwrap();
+        assert_eq!(first, second);
+        assert!(keypair_from_suri("//Alice/soft", SubstrateScheme::Ed25519).is_err());
+    }
+}
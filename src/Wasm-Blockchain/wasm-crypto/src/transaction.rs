@@ -0,0 +1,367 @@
+//! 以太坊交易
+//!
+//! 支持三种交易类型：
+//! - 0：legacy，按 EIP-155 在签名内容中加入链ID防重放，v = chainId * 2 + 35 + recovery_id
+//! - 1：EIP-2930 访问列表交易，`0x01 || rlp([chainId, nonce, gasPrice, gas, to, value, data, accessList, yParity, r, s])`
+//! - 2：EIP-1559 动态费用交易，`0x02 || rlp([chainId, nonce, maxPriorityFeePerGas, maxFeePerGas, gas, to, value, data, accessList, yParity, r, s])`
+//!
+//! 交易 JSON 字段与 ethers、viem 和 JSON-RPC 一致（camelCase），数值可为数字或十进制/0x十六进制字符串。
+//! 交易哈希为 Keccak-256(签名后的原始交易)。
+
+use serde_json::{Map, Value};
+use web3::types::U256;
+
+use crate::chain;
+use crate::error::WalletError;
+use crate::ethereum::{self, keccak256, parse_integer};
+use crate::rlp::{self, Item};
+
+/// 交易类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TxType {
+    Legacy,
+    AccessList,
+    DynamicFee,
+}
+
+impl TxType {
+    /// 交易类型编号，即类型化交易编码的首字节
+    pub fn id(self) -> u8 {
+        match self {
+            TxType::Legacy => 0,
+            TxType::AccessList => 1,
+            TxType::DynamicFee => 2,
+        }
+    }
+
+    /// 解析 `type` 字段：编号（数字或0x十六进制）或 viem 的名称
+    fn parse(value: &Value) -> Result<Self, String> {
+        let id = match value {
+            Value::String(name) => match name.trim().to_lowercase().as_str() {
+                "legacy" => return Ok(TxType::Legacy),
+                "eip2930" => return Ok(TxType::AccessList),
+                "eip1559" => return Ok(TxType::DynamicFee),
+                _ => parse_integer(value)?.1,
+            },
+            _ => parse_integer(value)?.1,
+        };
+        if id > U256::from(2) {
+            return Err(format!("Unsupported transaction type: {}", value));
+        }
+        match id.as_u64() {
+            0 => Ok(TxType::Legacy),
+            1 => Ok(TxType::AccessList),
+            _ => Ok(TxType::DynamicFee),
+        }
+    }
+}
+
+/// 访问列表中的一项
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccessListItem {
+    pub address: [u8; 20],
+    pub storage_keys: Vec<[u8; 32]>,
+}
+
+/// 待签名的交易
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transaction {
+    pub tx_type: TxType,
+    pub chain_id: u64,
+    pub nonce: U256,
+    pub gas_limit: U256,
+    /// legacy 和 EIP-2930 交易的 gasPrice
+    pub gas_price: U256,
+    pub max_fee_per_gas: U256,
+    pub max_priority_fee_per_gas: U256,
+    /// None 表示部署合约
+    pub to: Option<[u8; 20]>,
+    pub value: U256,
+    pub data: Vec<u8>,
+    pub access_list: Vec<AccessListItem>,
+}
+
+/// 签名后的交易
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedTransaction {
+    pub tx_type: TxType,
+    /// 可直接通过 `eth_sendRawTransaction` 广播的原始交易
+    pub raw: Vec<u8>,
+    pub hash: [u8; 32],
+}
+
+impl Transaction {
+    /// 解析交易 JSON；未给出 `type` 时按字段推断：有 `maxFeePerGas` 为 2，有 `accessList` 为 1，否则为 0
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| format!("Invalid transaction JSON: {}", e))?;
+        let fields = value
+            .as_object()
+            .ok_or_else(|| "Transaction must be a JSON object".to_string())?;
+
+        let tx_type = match field(fields, &["type"]) {
+            Some(value) => TxType::parse(value)?,
+            None if field(fields, &["maxFeePerGas"]).is_some() => TxType::DynamicFee,
+            None if field(fields, &["accessList"]).is_some() => TxType::AccessList,
+            None => TxType::Legacy,
+        };
+        // 动态费用交易的两个费用字段必填，其他类型不使用
+        let fee = |name: &str| match tx_type {
+            TxType::DynamicFee => required_uint(fields, &[name]),
+            _ => Ok(U256::zero()),
+        };
+
+        Ok(Transaction {
+            tx_type,
+            chain_id: match required_uint(fields, &["chainId"])? {
+                chain_id if chain_id.bits() <= 64 => chain_id.as_u64(),
+                _ => return Err("chainId: Value out of range".to_string()),
+            },
+            nonce: required_uint(fields, &["nonce"])?,
+            gas_limit: required_uint(fields, &["gas", "gasLimit"])?,
+            gas_price: match tx_type {
+                TxType::DynamicFee => U256::zero(),
+                _ => required_uint(fields, &["gasPrice"])?,
+            },
+            max_fee_per_gas: fee("maxFeePerGas")?,
+            max_priority_fee_per_gas: fee("maxPriorityFeePerGas")?,
+            to: match field(fields, &["to"]) {
+                Some(to) => Some(parse_address(to).map_err(|e| format!("to: {}", e))?),
+                None => None,
+            },
+            value: match field(fields, &["value"]) {
+                Some(value) => uint(value).map_err(|e| format!("value: {}", e))?,
+                None => U256::zero(),
+            },
+            data: match field(fields, &["data", "input"]) {
+                Some(data) => parse_hex(data).map_err(|e| format!("data: {}", e))?,
+                None => Vec::new(),
+            },
+            access_list: match field(fields, &["accessList"]) {
+                Some(list) if tx_type != TxType::Legacy => parse_access_list(list)?,
+                Some(_) => return Err("Legacy transactions cannot have an access list".to_string()),
+                None => Vec::new(),
+            },
+        })
+    }
+
+    /// 签名摘要：legacy 为 keccak256(rlp([..., chainId, 0, 0]))，类型化交易为 keccak256(type || rlp([...]))
+    pub fn signing_hash(&self) -> [u8; 32] {
+        let mut fields = self.payload();
+        if self.tx_type == TxType::Legacy {
+            fields.extend([
+                Item::uint(U256::from(self.chain_id)),
+                Item::uint(U256::zero()),
+                Item::uint(U256::zero()),
+            ]);
+        }
+        keccak256(&self.envelope(fields))
+    }
+
+    /// 用对 `signing_hash` 的签名 r || s || v 生成原始交易，v 可为 27/28（`ethereum::sign_digest`）或 0/1
+    pub fn encode_signed(&self, signature: &[u8; 65]) -> Result<SignedTransaction, WalletError> {
+        let parity = match signature[64] {
+            v @ 27..=28 => u64::from(v - 27),
+            v @ 0..=1 => u64::from(v),
+            v => {
+                return Err(WalletError::invalid_signature(format!(
+                    "Invalid recovery id: {}",
+                    v
+                )));
+            }
+        };
+        let v = match self.tx_type {
+            TxType::Legacy => U256::from(self.chain_id) * 2 + 35 + parity,
+            _ => U256::from(parity),
+        };
+
+        let mut fields = self.payload();
+        fields.extend([
+            Item::uint(v),
+            Item::uint(U256::from_big_endian(&signature[..32])),
+            Item::uint(U256::from_big_endian(&signature[32..64])),
+        ]);
+        let raw = self.envelope(fields);
+        Ok(SignedTransaction {
+            tx_type: self.tx_type,
+            hash: keccak256(&raw),
+            raw,
+        })
+    }
+
+    /// 签名字段之前的各字段，顺序由交易类型决定
+    fn payload(&self) -> Vec<Item> {
+        let to = Item::bytes(self.to.as_ref().map_or(&[][..], |to| &to[..]));
+        let common = [
+            Item::uint(self.gas_limit),
+            to,
+            Item::uint(self.value),
+            Item::bytes(&self.data),
+        ];
+        match self.tx_type {
+            TxType::Legacy => [Item::uint(self.nonce), Item::uint(self.gas_price)]
+                .into_iter()
+                .chain(common)
+                .collect(),
+            TxType::AccessList => [
+                Item::uint(U256::from(self.chain_id)),
+                Item::uint(self.nonce),
+                Item::uint(self.gas_price),
+            ]
+            .into_iter()
+            .chain(common)
+            .chain([self.access_list_item()])
+            .collect(),
+            TxType::DynamicFee => [
+                Item::uint(U256::from(self.chain_id)),
+                Item::uint(self.nonce),
+                Item::uint(self.max_priority_fee_per_gas),
+                Item::uint(self.max_fee_per_gas),
+            ]
+            .into_iter()
+            .chain(common)
+            .chain([self.access_list_item()])
+            .collect(),
+        }
+    }
+
+    fn access_list_item(&self) -> Item {
+        Item::List(
+            self.access_list
+                .iter()
+                .map(|entry| {
+                    Item::List(vec![
+                        Item::bytes(&entry.address),
+                        Item::List(
+                            entry
+                                .storage_keys
+                                .iter()
+                                .map(|key| Item::bytes(key))
+                                .collect(),
+                        ),
+                    ])
+                })
+                .collect(),
+        )
+    }
+
+    /// legacy 交易直接为 RLP 列表，类型化交易在前面加类型字节
+    fn envelope(&self, fields: Vec<Item>) -> Vec<u8> {
+        let encoded = rlp::encode(&Item::List(fields));
+        match self.tx_type {
+            TxType::Legacy => encoded,
+            tx_type => [vec![tx_type.id()], encoded].concat(),
+        }
+    }
+}
+
+/// 按别名查找字段，null 视为未给出
+fn field<'a>(fields: &'a Map<String, Value>, names: &[&str]) -> Option<&'a Value> {
+    names
+        .iter()
+        .filter_map(|name| fields.get(*name))
+        .find(|value| !value.is_null())
+}
+
+fn uint(value: &Value) -> Result<U256, String> {
+    match parse_integer(value)? {
+        (false, value) => Ok(value),
+        (true, _) => Err("Value must not be negative".to_string()),
+    }
+}
+
+fn required_uint(fields: &Map<String, Value>, names: &[&str]) -> Result<U256, String> {
+    let value = field(fields, names).ok_or_else(|| format!("Missing field '{}'", names[0]))?;
+    uint(value).map_err(|e| format!("{}: {}", names[0], e))
+}
+
+fn parse_hex(value: &Value) -> Result<Vec<u8>, String> {
+    let text = value
+        .as_str()
+        .ok_or_else(|| "Value must be a hex string".to_string())?;
+    chain::decode_hex(text).map_err(|e| format!("Invalid hex value: {}", e))
+}
+
+/// 解析地址，大小写混合时校验 EIP-55 校验和
+fn parse_address(value: &Value) -> Result<[u8; 20], String> {
+    let text = value
+        .as_str()
+        .ok_or_else(|| "Address must be a string".to_string())?;
+    ethereum::validate_address(text, false, None)?;
+    let bytes = parse_hex(value)?;
+    <[u8; 20]>::try_from(bytes.as_slice()).map_err(|_| "Address must be 20 bytes".to_string())
+}
+
+fn parse_access_list(value: &Value) -> Result<Vec<AccessListItem>, String> {
+    let entries = value
+        .as_array()
+        .ok_or_else(|| "accessList must be an array".to_string())?;
+    entries
+        .iter()
+        .map(|entry| {
+            let address = entry
+                .get("address")
+                .ok_or_else(|| "accessList entry is missing 'address'".to_string())
+                .and_then(parse_address)?;
+            let storage_keys = match entry.get("storageKeys") {
+                Some(Value::Array(keys)) => keys
+                    .iter()
+                    .map(|key| {
+                        let bytes = parse_hex(key)?;
+                        <[u8; 32]>::try_from(bytes.as_slice()).map_err(|_| {
+                            format!("Storage key must be 32 bytes, got {}", bytes.len())
+                        })
+                    })
+                    .collect::<Result<_, _>>()?,
+                None => Vec::new(),
+                Some(_) => return Err("storageKeys must be an array".to_string()),
+            };
+            Ok(AccessListItem {
+                address,
+                storage_keys,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{chain, ethereum};
+
+    #[test]
+    fn signs_eip155_example() {
+        // EIP-155 规范中的示例交易
+        let transaction = Transaction::from_json(
+            r#"{"nonce":9,"gasPrice":"20000000000","gas":21000,"to":"0x3535353535353535353535353535353535353535","value":"1000000000000000000","chainId":1}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            hex::encode(transaction.signing_hash()),
+            "daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53"
+        );
+        let secret_key = chain::parse_secp256k1_secret(
+            "0x4646464646464646464646464646464646464646464646464646464646464646",
+        )
+        .unwrap();
+        let signature = ethereum::sign_digest(&secret_key, transaction.signing_hash()).unwrap();
+        let signed = transaction.encode_signed(&signature).unwrap();
+        assert_eq!(
+            hex::encode(signed.raw),
+            "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_recovery_id() {
+        let transaction =
+            Transaction::from_json(r#"{"chainId":1,"nonce":0,"gas":21000,"gasPrice":1}"#).unwrap();
+        let mut signature = [1u8; 65];
+        signature[64] = 28;
+        let legacy = transaction.encode_signed(&signature).unwrap();
+        signature[64] = 1;
+        assert_eq!(transaction.encode_signed(&signature).unwrap(), legacy);
+        signature[64] = 2;
+        assert!(transaction.encode_signed(&signature).is_err());
+    }
+}
@@ -0,0 +1,178 @@
+//! Tron 账户
+//!
+//! secp256k1 密钥沿 `m/44'/195'/0'/0/n` 派生，地址为以太坊地址的20字节加上 0x41 前缀，
+//! 再做 base58check 编码（T 开头）。
+
+use secp256k1::{PublicKey, Secp256k1};
+
+use crate::bitcoin::{base58check_decode, base58check_encode};
+use crate::chain::{self, ChainAccount, ChainAdapter};
+use crate::error::WalletError;
+use crate::ethereum;
+use crate::secret::Secret;
+
+/// Tron 的 SLIP-44 币种编号
+pub const COIN_TYPE: u32 = 195;
+
+/// 主网地址前缀
+pub const ADDRESS_PREFIX: u8 = 0x41;
+
+/// TronWeb `signMessageV2` 的消息前缀，后接十进制消息长度
+const MESSAGE_PREFIX: &str = "\x19TRON Signed Message:\n";
+
+/// TronWeb `signMessageV2` 的消息摘要：Keccak-256("\x19TRON Signed Message:\n" || 十进制长度 || 消息)
+pub fn message_hash(message: &[u8]) -> [u8; 32] {
+    ethereum::prefixed_message_hash(MESSAGE_PREFIX, message)
+}
+
+/// 将20字节以太坊地址编码为 Tron base58 地址
+pub fn encode_address(address: &[u8; 20]) -> String {
+    base58check_encode(ADDRESS_PREFIX, address)
+}
+
+/// 十六进制地址转 base58 地址
+///
+/// 接受带 41 前缀的21字节 Tron 十六进制地址，或20字节以太坊形式（可带0x前缀）。
+pub fn hex_to_base58(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    let bytes = hex::decode(value.strip_prefix("0x").unwrap_or(value))
+        .map_err(|e| format!("Invalid hex address: {}", e))?;
+
+    let body = match bytes.len() {
+        21 if bytes[0] == ADDRESS_PREFIX => &bytes[1..],
+        21 => return Err(format!("Invalid Tron address prefix: 0x{:02x}", bytes[0])),
+        20 => &bytes[..],
+        len => return Err(format!("Invalid Tron address length: {} bytes", len)),
+    };
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(body);
+    Ok(encode_address(&address))
+}
+
+/// base58 地址转带 41 前缀的十六进制地址，校验和或前缀错误时返回错误
+pub fn base58_to_hex(address: &str) -> Result<String, String> {
+    let (version, payload) = base58check_decode(address.trim())?;
+    if version != ADDRESS_PREFIX {
+        return Err(format!("Invalid Tron address prefix: 0x{:02x}", version));
+    }
+    if payload.len() != 20 {
+        return Err(format!(
+            "Invalid Tron address length: {} bytes",
+            payload.len() + 1
+        ));
+    }
+    Ok(format!("{:02x}{}", version, hex::encode(payload)))
+}
+
+/// Tron 适配器：`m/44'/195'/0'/0/n`，密钥为不带0x前缀的十六进制，消息签名与 TronWeb `signMessageV2` 一致
+pub struct TronAdapter;
+
+pub fn adapter(chain_type: &str) -> Result<Option<Box<dyn ChainAdapter>>, WalletError> {
+    match chain_type.to_lowercase().as_str() {
+        "tron" | "trx" => Ok(Some(Box::new(TronAdapter))),
+        _ => Ok(None),
+    }
+}
+
+impl ChainAdapter for TronAdapter {
+    fn name(&self) -> &'static str {
+        "tron"
+    }
+
+    fn account_path(&self, index: u32) -> String {
+        format!("m/44'/{}'/0'/0/{}", COIN_TYPE, index)
+    }
+
+    fn derive_account(&self, seed: &[u8], path: &str) -> Result<ChainAccount, WalletError> {
+        let secret_key = chain::derive_secp256k1(seed, path)?;
+        let public = PublicKey::from_secret_key(&Secp256k1::signing_only(), &secret_key)
+            .serialize_uncompressed();
+        Ok(ChainAccount {
+            public_key: hex::encode(public),
+            private_key: Secret::new(hex::encode(secret_key.secret_bytes())),
+            address: self.format_address(&public)?,
+        })
+    }
+
+    fn format_address(&self, public_key: &[u8]) -> Result<String, WalletError> {
+        let address =
+            ethereum::address_from_public_key(public_key).map_err(WalletError::invalid_key)?;
+        Ok(encode_address(&address))
+    }
+
+    fn validate_address(&self, address: &str) -> Result<(), WalletError> {
+        base58_to_hex(address)
+            .map(|_| ())
+            .map_err(WalletError::invalid_address)
+    }
+
+    /// 对 `message_hash` 摘要签名，返回 r || s || v（v 为 27/28）
+    fn sign(&self, private_key: &str, message: &[u8]) -> Result<Vec<u8>, WalletError> {
+        let secret_key = chain::parse_secp256k1_secret(private_key)?;
+        Ok(ethereum::sign_digest(&secret_key, message_hash(message))?.to_vec())
+    }
+
+    fn verify(
+        &self,
+        public_key: &str,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, WalletError> {
+        let public = chain::parse_secp256k1_public(public_key)?;
+        chain::verify_ecdsa(&public, message_hash(message), signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derives_tronlink_address() {
+        let seed = crate::mnemonic::mnemonic_to_seed(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "",
+        );
+        let account = TronAdapter
+            .derive_account(&seed, &TronAdapter.default_path())
+            .unwrap();
+        assert_eq!(account.address, "TUEZSdKsoDHQMeZwihtdoBiN46zxhGWYdH");
+    }
+
+    #[test]
+    fn converts_hex_addresses() {
+        // USDT 合约地址
+        assert_eq!(
+            base58_to_hex("TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t").unwrap(),
+            "41a614f803b6fd780986a42c78ec9c7f77e6ded13c"
+        );
+        assert_eq!(
+            hex_to_base58("0xa614f803b6fd780986a42c78ec9c7f77e6ded13c").unwrap(),
+            "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"
+        );
+        assert!(base58_to_hex("1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA").is_err());
+    }
+
+    #[test]
+    fn signs_with_tron_message_prefix() {
+        let key = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+        let signature = TronAdapter.sign(key, b"Some data").unwrap();
+        assert!(matches!(signature[64], 27 | 28));
+        assert_ne!(
+            signature,
+            crate::ethereum::EthereumAdapter
+                .sign(key, b"Some data")
+                .unwrap()
+        );
+        let secret_key = chain::parse_secp256k1_secret(key).unwrap();
+        let public_key = PublicKey::from_secret_key(&Secp256k1::new(), &secret_key);
+        let public_key = hex::encode(public_key.serialize_uncompressed());
+        assert!(TronAdapter
+            .verify(&public_key, b"Some data", &signature)
+            .unwrap());
+        assert!(!TronAdapter
+            .verify(&public_key, b"Other data", &signature)
+            .unwrap());
+    }
+}
@@ -0,0 +1,593 @@
+//! 原生 Rust 接口
+//!
+//! `Wallet` 持有助记词和口令，按链类型派生 `Account`；`Account` 用链适配器签名和验签，
+//! 签名统一为 `Signature`。所有函数返回 `Result<_, WalletError>`，不依赖 JS，
+//! 后端服务和测试可在宿主平台直接链接；wasm 导出只是这里的薄封装。
+
+use std::fmt;
+use std::rc::Rc;
+
+use bip39::{Language, Mnemonic};
+use sp_core::{ecdsa, hashing::blake2_256, Pair};
+
+use crate::bip32::HARDENED_OFFSET;
+use crate::chain::{self, ChainAdapter};
+use crate::cosmos::CosmosAdapter;
+use crate::device_kdf::{self, DeviceKdfScheme, LegacyDeviceAdapter};
+use crate::eip712::{TypedData, TypedDataHash};
+use crate::error::WalletError;
+use crate::secret::Secret;
+use crate::transaction::{SignedTransaction, Transaction};
+use crate::{ethereum, mnemonic, substrate};
+
+/// 单次调用最多枚举的账户数量
+pub const MAX_ACCOUNT_BATCH: u32 = 100;
+
+/// 由助记词（及可选 BIP39 口令）构成的钱包
+#[derive(Clone, Debug)]
+pub struct Wallet {
+    mnemonic: Secret<String>,
+    language: Language,
+    passphrase: Secret<String>,
+    /// 由 v1 设备ID方案生成，账户按旧版算法派生
+    legacy_device: bool,
+}
+
+impl Wallet {
+    /// 校验助记词（单词数量、词表和校验和）并创建钱包，语言自动识别
+    pub fn from_mnemonic(phrase: &str) -> Result<Self, WalletError> {
+        let parsed = mnemonic::parse_mnemonic(phrase)?;
+        Ok(Wallet {
+            mnemonic: Secret::new(phrase.to_string()),
+            language: parsed.language(),
+            passphrase: Secret::new(String::new()),
+            legacy_device: false,
+        })
+    }
+
+    /// 用系统安全随机数生成新钱包
+    pub fn generate(word_count: usize, language: Language) -> Result<Self, WalletError> {
+        let generated = mnemonic::generate_mnemonic(word_count, language)?;
+        Ok(Self::from_parsed(&generated))
+    }
+
+    /// 从设备ID确定性地生成钱包，`strength_bits` 为熵强度（128–256 位）
+    ///
+    /// v1 方案只用于找回旧版生成的钱包，必须为128位英文助记词，账户派生见 `LegacyDeviceAdapter`。
+    pub fn from_device_id(
+        device_id: &str,
+        scheme: DeviceKdfScheme,
+        salt: Option<&[u8]>,
+        strength_bits: u32,
+        language: Language,
+    ) -> Result<Self, WalletError> {
+        if device_id.len() != 10 || !device_id.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(WalletError::invalid_argument(format!(
+                "Device ID must be exactly 10 alphanumeric characters, got {} characters with invalid format",
+                device_id.len()
+            )));
+        }
+
+        let legacy_device = scheme == DeviceKdfScheme::LegacySha256;
+        if legacy_device && (strength_bits != 128 || language != Language::English) {
+            return Err(WalletError::invalid_argument(
+                "Scheme v1 only produces 128-bit English mnemonics",
+            ));
+        }
+
+        let hash = Secret::new(device_kdf::derive_device_entropy(scheme, device_id, salt)?);
+        let entropy = &hash.expose()[..mnemonic::entropy_len(strength_bits)?];
+        log_debug!("Entropy length: {} bytes", entropy.len());
+        let generated = Mnemonic::from_entropy_in(language, entropy).map_err(|e| {
+            WalletError::InvalidMnemonic {
+                reason: e.to_string(),
+            }
+        })?;
+        Ok(Wallet {
+            legacy_device,
+            ..Self::from_parsed(&generated)
+        })
+    }
+
+    fn from_parsed(parsed: &Mnemonic) -> Self {
+        Wallet {
+            mnemonic: Secret::new(parsed.words().collect::<Vec<&str>>().join(" ")),
+            language: parsed.language(),
+            passphrase: Secret::new(String::new()),
+            legacy_device: false,
+        }
+    }
+
+    /// 设置 BIP39 口令（空字符串表示不使用口令）
+    pub fn with_passphrase(mut self, passphrase: &str) -> Self {
+        self.passphrase = Secret::new(passphrase.to_string());
+        self
+    }
+
+    pub fn mnemonic(&self) -> &Secret<String> {
+        &self.mnemonic
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// 词表语言代码，如 `english`、`chinese_simplified`
+    pub fn language_code(&self) -> &'static str {
+        mnemonic::language_code(self.language)
+    }
+
+    /// 助记词单词数量
+    pub fn word_count(&self) -> usize {
+        self.mnemonic.expose().split_whitespace().count()
+    }
+
+    /// BIP39 种子（PBKDF2-HMAC-SHA512）
+    pub fn bip39_seed(&self) -> Secret<[u8; mnemonic::SEED_LEN]> {
+        Secret::new(mnemonic::mnemonic_to_seed(
+            self.mnemonic.expose(),
+            self.passphrase.expose(),
+        ))
+    }
+
+    /// 沿路径派生账户，`path` 为空时使用链的默认路径
+    pub fn derive(&self, chain_type: &str, path: Option<&str>) -> Result<Account, WalletError> {
+        let adapter = self.adapter(chain_type)?;
+        let path = match path.map(str::trim) {
+            Some(path) if !path.is_empty() => path.to_string(),
+            _ => adapter.default_path(),
+        };
+        let seed = self.adapter_seed(adapter.as_ref())?;
+        Account::derive(adapter, chain_type.trim(), seed.expose(), path)
+    }
+
+    /// 批量派生 `start_index` 起的 `count` 个账户，种子只计算一次
+    pub fn derive_accounts(
+        &self,
+        chain_type: &str,
+        start_index: u32,
+        count: u32,
+    ) -> Result<Vec<Account>, WalletError> {
+        if count == 0 || count > MAX_ACCOUNT_BATCH {
+            return Err(WalletError::invalid_argument(format!(
+                "Account count must be between 1 and {}, got {}",
+                MAX_ACCOUNT_BATCH, count
+            )));
+        }
+        let end_index = match start_index.checked_add(count) {
+            Some(end) if end <= HARDENED_OFFSET => end,
+            _ => {
+                return Err(WalletError::invalid_argument(format!(
+                    "Account index range out of bounds: start {} count {}",
+                    start_index, count
+                )));
+            }
+        };
+
+        if self.legacy_device && (start_index, count) != (0, 1) {
+            return Err(WalletError::unsupported(
+                "Scheme v1 wallets have a single account",
+            ));
+        }
+
+        let adapter = self.adapter(chain_type)?;
+        let seed = self.adapter_seed(adapter.as_ref())?;
+        (start_index..end_index)
+            .map(|index| {
+                let path = adapter.account_path(index);
+                Account::derive(adapter.clone(), chain_type.trim(), seed.expose(), path)
+            })
+            .collect()
+    }
+
+    /// 按 bech32 前缀和币种编号派生 Cosmos SDK 链账户，路径为 `m/44'/coin_type'/0'/0/index`
+    pub fn derive_cosmos(
+        &self,
+        prefix: &str,
+        coin_type: u32,
+        index: u32,
+    ) -> Result<Account, WalletError> {
+        if coin_type >= HARDENED_OFFSET || index >= HARDENED_OFFSET {
+            return Err(WalletError::invalid_argument(format!(
+                "Coin type or account index out of range: {} / {}",
+                coin_type, index
+            )));
+        }
+        if self.legacy_device {
+            return Err(WalletError::unsupported(
+                "Scheme v1 wallets cannot derive Cosmos accounts",
+            ));
+        }
+        let adapter: Rc<dyn ChainAdapter> = Rc::new(CosmosAdapter::new(prefix, coin_type)?);
+        let path = adapter.account_path(index);
+        let seed = self.adapter_seed(adapter.as_ref())?;
+        Account::derive(adapter, "cosmos", seed.expose(), path)
+    }
+
+    fn adapter(&self, chain_type: &str) -> Result<Rc<dyn ChainAdapter>, WalletError> {
+        if self.legacy_device {
+            return Ok(Rc::new(LegacyDeviceAdapter::new(chain_type)));
+        }
+        Ok(Rc::from(chain::adapter_for(chain_type)?))
+    }
+
+    fn adapter_seed(&self, adapter: &dyn ChainAdapter) -> Result<Secret<Vec<u8>>, WalletError> {
+        if !self.passphrase.expose().is_empty() {
+            log_debug!("Using BIP39 passphrase");
+        }
+        adapter.seed(self.mnemonic.expose(), self.passphrase.expose())
+    }
+}
+
+/// 派生出的账户，密钥和地址格式由链决定
+#[derive(Clone)]
+pub struct Account {
+    pub chain_type: String,
+    pub path: String,
+    pub public_key: String,
+    pub private_key: Secret<String>,
+    pub address: String,
+    adapter: Rc<dyn ChainAdapter>,
+}
+
+impl Account {
+    fn derive(
+        adapter: Rc<dyn ChainAdapter>,
+        chain_type: &str,
+        seed: &[u8],
+        path: String,
+    ) -> Result<Self, WalletError> {
+        log_debug!("Deriving {} account at '{}'", adapter.name(), path);
+        let account = adapter.derive_account(seed, &path)?;
+        Ok(Account {
+            chain_type: chain_type.to_string(),
+            path,
+            public_key: account.public_key,
+            private_key: account.private_key,
+            address: account.address,
+            adapter,
+        })
+    }
+
+    /// 用账户私钥对消息签名，签名格式见 `ChainAdapter::sign`
+    pub fn sign(&self, message: &[u8]) -> Result<Signature, WalletError> {
+        self.adapter
+            .sign(self.private_key.expose(), message)
+            .map(Signature::from_bytes)
+    }
+
+    /// 用账户公钥验证签名
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> Result<bool, WalletError> {
+        self.adapter
+            .verify(&self.public_key, message, signature.as_bytes())
+    }
+}
+
+impl fmt::Debug for Account {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Account")
+            .field("chain_type", &self.chain_type)
+            .field("path", &self.path)
+            .field("public_key", &self.public_key)
+            .field("private_key", &self.private_key)
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+/// 链上签名的原始字节
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature(Vec<u8>);
+
+impl Signature {
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Signature(bytes)
+    }
+
+    /// 解析十六进制签名，允许带0x前缀
+    pub fn from_hex(value: &str) -> Result<Self, WalletError> {
+        chain::decode_hex(value)
+            .map(Signature)
+            .map_err(|e| WalletError::invalid_signature(format!("Invalid hex signature: {}", e)))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// 带0x前缀的十六进制字符串
+    pub fn to_hex(&self) -> String {
+        format!("0x{}", hex::encode(&self.0))
+    }
+}
+
+/// 恢复签名者时消息的摘要方式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecoveryScheme {
+    /// EIP-191 `personal_sign`，即 `eth_personal_sign` 的签名
+    Eip191,
+    /// 消息本身即32字节摘要（如交易哈希或 EIP-712 摘要），恢复出以太坊地址
+    Digest,
+    /// sp-core ecdsa（消息先做 blake2_256），即 `sign_legacy_ecdsa` 的签名，恢复出 SS58 地址
+    SubstrateEcdsa,
+}
+
+impl RecoveryScheme {
+    /// 解析方案名称，空字符串默认为 eip191
+    pub fn parse(name: &str) -> Result<Self, WalletError> {
+        match name.trim().to_lowercase().as_str() {
+            "" | "eip191" | "personal_sign" => Ok(RecoveryScheme::Eip191),
+            "digest" | "raw" => Ok(RecoveryScheme::Digest),
+            "substrate_ecdsa" | "sp_core" | "legacy" => Ok(RecoveryScheme::SubstrateEcdsa),
+            _ => Err(WalletError::invalid_argument(format!(
+                "Unsupported recovery scheme: '{}'",
+                name
+            ))),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            RecoveryScheme::Eip191 => "eip191",
+            RecoveryScheme::Digest => "digest",
+            RecoveryScheme::SubstrateEcdsa => "substrate_ecdsa",
+        }
+    }
+}
+
+/// 从签名恢复出的签名者
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecoveredSigner {
+    /// 带0x前缀的公钥：以太坊为未压缩65字节，Substrate ecdsa 为压缩33字节
+    pub public_key: String,
+    /// 以太坊为 EIP-55 校验和地址，Substrate ecdsa 为通用前缀（42）的 SS58 地址
+    pub address: String,
+}
+
+/// 从65字节签名 r || s || v 恢复签名者的公钥和地址
+///
+/// 比较以太坊地址时应忽略大小写；恢复总会得到某个公钥，签名是否出自预期账户需由调用方比较地址。
+pub fn recover_signer(
+    message: &[u8],
+    signature: &Signature,
+    scheme: RecoveryScheme,
+) -> Result<RecoveredSigner, WalletError> {
+    let digest = match scheme {
+        RecoveryScheme::Eip191 => ethereum::personal_message_hash(message),
+        RecoveryScheme::Digest => <[u8; 32]>::try_from(message).map_err(|_| {
+            WalletError::invalid_argument(format!("Digest must be 32 bytes, got {}", message.len()))
+        })?,
+        RecoveryScheme::SubstrateEcdsa => blake2_256(message),
+    };
+    let public = chain::recover_secp256k1(digest, signature.as_bytes())?;
+
+    match scheme {
+        RecoveryScheme::SubstrateEcdsa => {
+            let public = public.serialize();
+            Ok(RecoveredSigner {
+                public_key: format!("0x{}", hex::encode(public)),
+                address: substrate::ss58_encode(
+                    &blake2_256(&public),
+                    substrate::GENERIC_SUBSTRATE_PREFIX,
+                ),
+            })
+        }
+        RecoveryScheme::Eip191 | RecoveryScheme::Digest => {
+            let public = public.serialize_uncompressed();
+            Ok(RecoveredSigner {
+                public_key: format!("0x{}", hex::encode(public)),
+                address: ethereum::to_checksum_address(
+                    &ethereum::public_key_to_address(&public),
+                    None,
+                ),
+            })
+        }
+    }
+}
+
+/// 按链类型校验地址格式和校验和
+pub fn validate_address(chain_type: &str, address: &str) -> Result<(), WalletError> {
+    chain::adapter_for(chain_type)?.validate_address(address)
+}
+
+/// 用该链钱包导出格式的私钥对消息签名
+pub fn sign_message(
+    chain_type: &str,
+    private_key: &str,
+    message: &[u8],
+) -> Result<Signature, WalletError> {
+    chain::adapter_for(chain_type)?
+        .sign(private_key, message)
+        .map(Signature::from_bytes)
+}
+
+/// 用该链钱包导出格式的公钥验证签名
+pub fn verify_signature(
+    chain_type: &str,
+    public_key: &str,
+    message: &[u8],
+    signature: &Signature,
+) -> Result<bool, WalletError> {
+    chain::adapter_for(chain_type)?.verify(public_key, message, signature.as_bytes())
+}
+
+/// 以太坊 `personal_sign`（EIP-191），私钥为十六进制 secp256k1 私钥，返回 r || s || v（v 为 27/28）
+pub fn eth_personal_sign(private_key: &str, message: &[u8]) -> Result<Signature, WalletError> {
+    let secret_key = chain::parse_secp256k1_secret(private_key)?;
+    ethereum::personal_sign(&secret_key, message)
+        .map(|signature| Signature::from_bytes(signature.to_vec()))
+}
+
+/// EIP-712 哈希：解析 `eth_signTypedData_v4` 的 JSON，返回域分隔符、消息结构哈希和摘要
+pub fn hash_typed_data(typed_data: &str) -> Result<TypedDataHash, WalletError> {
+    TypedData::from_json(typed_data)
+        .and_then(|typed_data| typed_data.hash())
+        .map_err(WalletError::invalid_argument)
+}
+
+/// `eth_signTypedData_v4`：对 EIP-712 摘要签名，返回中间哈希和 r || s || v（v 为 27/28）
+pub fn eth_sign_typed_data(
+    private_key: &str,
+    typed_data: &str,
+) -> Result<(TypedDataHash, Signature), WalletError> {
+    let secret_key = chain::parse_secp256k1_secret(private_key)?;
+    let hash = hash_typed_data(typed_data)?;
+    let signature = ethereum::sign_digest(&secret_key, hash.digest)?;
+    Ok((hash, Signature::from_bytes(signature.to_vec())))
+}
+
+/// 签名以太坊交易（legacy/EIP-155、EIP-2930 或 EIP-1559），返回原始交易和交易哈希
+pub fn sign_transaction(
+    private_key: &str,
+    transaction: &str,
+) -> Result<SignedTransaction, WalletError> {
+    let secret_key = chain::parse_secp256k1_secret(private_key)?;
+    let transaction = Transaction::from_json(transaction).map_err(WalletError::invalid_argument)?;
+    let signature = ethereum::sign_digest(&secret_key, transaction.signing_hash())?;
+    transaction.encode_signed(&signature)
+}
+
+/// 旧版签名：sp-core ecdsa（消息先做 blake2_256），私钥为带0x前缀的32字节十六进制
+pub fn sign_legacy_ecdsa(private_key: &str, message: &[u8]) -> Result<Signature, WalletError> {
+    if !private_key.starts_with("0x") || private_key.len() != 66 {
+        return Err(WalletError::invalid_key(
+            "Private key must be 0x-prefixed 32-byte hex",
+        ));
+    }
+    let private_key_bytes =
+        Secret::new(hex::decode(&private_key[2..]).map_err(|e| {
+            WalletError::invalid_key(format!("Failed to decode private key: {}", e))
+        })?);
+    let pair = ecdsa::Pair::from_seed_slice(private_key_bytes.expose())
+        .map_err(|e| WalletError::invalid_key(format!("Failed to create key pair: {:?}", e)))?;
+    Ok(Signature::from_bytes(pair.sign(message).0.to_vec()))
+}
+
+/// 验证 `sign_legacy_ecdsa` 的签名，公钥为带0x前缀的压缩（33字节）或未压缩（65字节）十六进制
+pub fn verify_legacy_ecdsa(
+    public_key: &str,
+    message: &[u8],
+    signature: &Signature,
+) -> Result<bool, WalletError> {
+    if !public_key.starts_with("0x") {
+        return Err(WalletError::invalid_key(
+            "Public key must start with 0x prefix",
+        ));
+    }
+    let public_key_bytes = hex::decode(&public_key[2..])
+        .map_err(|e| WalletError::invalid_key(format!("Failed to decode public key: {}", e)))?;
+    if public_key_bytes.len() != 33 && public_key_bytes.len() != 65 {
+        return Err(WalletError::invalid_key(format!(
+            "Public key must be 33 or 65 bytes, got {}",
+            public_key_bytes.len()
+        )));
+    }
+
+    // sp-core ecdsa 使用压缩公钥，未压缩公钥先解析再压缩
+    let public = secp256k1::PublicKey::from_slice(&public_key_bytes)
+        .map_err(|e| WalletError::invalid_key(format!("Invalid secp256k1 public key: {}", e)))?;
+    let public = ecdsa::Public::from_raw(public.serialize());
+
+    let signature_array = <[u8; 65]>::try_from(signature.as_bytes()).map_err(|_| {
+        WalletError::invalid_signature(format!(
+            "Signature must be 65 bytes, got {}",
+            signature.as_bytes().len()
+        ))
+    })?;
+    Ok(ecdsa::Pair::verify(
+        &ecdsa::Signature::from_raw(signature_array),
+        message,
+        &public,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    const WEB3_KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    #[test]
+    fn derives_and_signs_with_account() {
+        let account = Wallet::from_mnemonic(PHRASE)
+            .unwrap()
+            .derive("ethereum", None)
+            .unwrap();
+        assert_eq!(
+            account.address,
+            "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+        );
+        let signature = account.sign(b"hello").unwrap();
+        assert!(account.verify(b"hello", &signature).unwrap());
+        assert!(!format!("{:?}", account).contains(account.private_key.expose()));
+    }
+
+    #[test]
+    fn recovers_personal_sign_signer() {
+        let signature = eth_personal_sign(WEB3_KEY, b"Some data").unwrap();
+        let signer = recover_signer(b"Some data", &signature, RecoveryScheme::Eip191).unwrap();
+        assert_eq!(signer.address, "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23");
+    }
+
+    #[test]
+    fn verifies_legacy_ecdsa_with_uncompressed_key() {
+        let account = Wallet::from_mnemonic(PHRASE)
+            .unwrap()
+            .derive("ethereum", None)
+            .unwrap();
+        let signature = sign_legacy_ecdsa(account.private_key.expose(), b"hi").unwrap();
+        assert!(verify_legacy_ecdsa(&account.public_key, b"hi", &signature).unwrap());
+        assert!(!verify_legacy_ecdsa(&account.public_key, b"ho", &signature).unwrap());
+    }
+
+    #[test]
+    fn reproduces_legacy_device_wallet() {
+        // 旧版 generate_wallet_from_device_id("abcDEF1234", ...) 的输出
+        let wallet = Wallet::from_device_id(
+            "abcDEF1234",
+            DeviceKdfScheme::LegacySha256,
+            None,
+            128,
+            Language::English,
+        )
+        .unwrap();
+        assert_eq!(
+            wallet.mnemonic().expose(),
+            "ripple pistol ritual behind true soda talk item federal tool east body"
+        );
+
+        let account = wallet.derive("ethereum", None).unwrap();
+        assert_eq!(
+            account.public_key,
+            "0x04037e50646b1ebf0f0c29905936d7be7a8f69d634424b1a4b681a647dc807145206"
+        );
+        assert_eq!(
+            account.private_key.expose(),
+            "0xc3dd63ab1948cee85edb2d3460253947c9f1f7ac84d1b3fe1555dac3b7b03450"
+        );
+        assert_eq!(
+            account.address,
+            "0xa0329a36efbe4a3c2902b75ed3ba93d000503727"
+        );
+        let signature = account.sign(b"hi").unwrap();
+        assert!(account.verify(b"hi", &signature).unwrap());
+
+        let account = wallet.derive("polkadot", None).unwrap();
+        assert_eq!(
+            account.address,
+            "037e50646b1ebf0f0c29905936d7be7a8f69d634424b1a4b681a647dc807145206"
+        );
+        assert_eq!(
+            account.private_key.expose(),
+            "c3dd63ab1948cee85edb2d3460253947c9f1f7ac84d1b3fe1555dac3b7b03450"
+        );
+
+        assert!(wallet
+            .clone()
+            .with_passphrase("x")
+            .derive("ethereum", None)
+            .is_err());
+        assert!(wallet.derive("ethereum", Some("m/44'/60'/0'/0/1")).is_err());
+    }
+}
@@ -0,0 +1,760 @@
+//! wasm-bindgen 导出
+//!
+//! 只负责参数默认值、调用 `wallet` 中的原生接口，以及把结果序列化为 `results` 中的结构；
+//! 错误统一经 `js_error` 转换为带错误码的 JS `WalletError`。
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::abi::{self, DecodedParam, Fragment, Revert};
+use crate::device_kdf::{self, DeviceKdfScheme};
+use crate::eip712::TypedDataHash;
+use crate::error::WalletError;
+use crate::logging::{self, LogLevel};
+use crate::results;
+use crate::secret::Secret;
+use crate::wallet::{self, Account, RecoveryScheme, Signature, Wallet};
+use crate::{chain, cosmos, ethereum, mnemonic, substrate, tron};
+
+/// 记录错误码并转换为 JS `WalletError`
+///
+/// 只记录错误码：错误信息会原样抛给调用方，且可能包含用户输入的单词。
+fn js_error(error: WalletError) -> JsValue {
+    log_error!("{}", error.code());
+    error.into()
+}
+
+/// 将结果结构序列化为 JS 对象，`None` 序列化为 null
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsValue> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| {
+            js_error(WalletError::SerializationFailed {
+                reason: e.to_string(),
+            })
+        })
+}
+
+/// 设置默认链类型为以太坊
+fn chain_or_default(chain_type: &str) -> &str {
+    log_debug!("Chain type: '{}'", chain_type);
+    if chain_type.is_empty() || chain_type.to_lowercase() == "ethereum" {
+        log_debug!("Using default chain type: ethereum");
+        "ethereum"
+    } else {
+        chain_type
+    }
+}
+
+fn wallet_result(
+    wallet: &Wallet,
+    account: Account,
+    scheme_version: Option<u32>,
+) -> results::WalletResult {
+    results::WalletResult {
+        mnemonic: wallet.mnemonic().clone(),
+        language: wallet.language_code().to_string(),
+        path: account.path,
+        public_key: account.public_key,
+        private_key: account.private_key,
+        address: account.address,
+        chain_type: account.chain_type,
+        scheme_version,
+    }
+}
+
+/// 设置日志级别：off、error、info 或 debug（调试构建默认 info，发布构建默认 off）
+#[wasm_bindgen]
+pub fn set_log_level(
+    #[wasm_bindgen(unchecked_param_type = "LogLevel")] level: &str,
+) -> Result<(), JsValue> {
+    let level = LogLevel::parse(level).map_err(|e| js_error(WalletError::invalid_argument(e)))?;
+    logging::set_level(level);
+    Ok(())
+}
+
+/// 当前日志级别
+#[wasm_bindgen(unchecked_return_type = "LogLevel")]
+pub fn get_log_level() -> String {
+    logging::level().name().to_string()
+}
+
+/// 从设备ID确定性地生成钱包
+///
+/// `scheme` 选择设备ID派生方案：默认 2 为 Argon2id，需要通过 `salt` 传入每次安装生成的十六进制盐；
+/// 1 为旧版无盐 SHA-256，只用于查找已有地址，必须显式传入。
+/// 返回结果中的 `schemeVersion` 记录所用方案。
+#[wasm_bindgen(unchecked_return_type = "WalletResult")]
+pub fn generate_wallet_from_device_id(
+    device_id: &str,
+    chain_type: &str,
+    passphrase: Option<String>,
+    strength: Option<u32>,
+    language: Option<String>,
+    scheme: Option<u32>,
+    salt: Option<String>,
+) -> Result<JsValue, JsValue> {
+    log_info!("Starting wallet generation from device ID");
+    let chain_type = chain_or_default(chain_type);
+
+    // 选择设备ID派生方案（默认 v2，旧版 v1 需显式指定）
+    let scheme = DeviceKdfScheme::from_version(scheme.unwrap_or(2)).map_err(js_error)?;
+    log_debug!("Device key derivation scheme: v{}", scheme.version());
+
+    let salt = salt
+        .as_deref()
+        .map(chain::decode_hex)
+        .transpose()
+        .map_err(|e| {
+            js_error(WalletError::invalid_argument(format!(
+                "Invalid installation salt: {}",
+                e
+            )))
+        })?;
+    let language = mnemonic::parse_language(language.as_deref().unwrap_or("")).map_err(js_error)?;
+
+    let wallet = Wallet::from_device_id(
+        device_id,
+        scheme,
+        salt.as_deref(),
+        strength.unwrap_or(mnemonic::DEFAULT_ENTROPY_BITS),
+        language,
+    )
+    .map_err(js_error)?
+    .with_passphrase(passphrase.as_deref().unwrap_or(""));
+    let account = wallet.derive(chain_type, None).map_err(js_error)?;
+
+    log_info!("Wallet generated for chain '{}'", chain_type);
+    to_js(&wallet_result(&wallet, account, Some(scheme.version())))
+}
+
+#[wasm_bindgen(unchecked_return_type = "WalletResult")]
+pub fn generate_wallet_from_mnemonic(
+    mnemonic_words: &str,
+    chain_type: &str,
+    passphrase: Option<String>,
+) -> Result<JsValue, JsValue> {
+    log_info!("Starting wallet generation from mnemonic");
+    let chain_type = chain_or_default(chain_type);
+
+    let wallet = Wallet::from_mnemonic(mnemonic_words)
+        .map_err(js_error)?
+        .with_passphrase(passphrase.as_deref().unwrap_or(""));
+    let account = wallet.derive(chain_type, None).map_err(js_error)?;
+
+    log_info!("Wallet generated for chain '{}'", chain_type);
+    to_js(&wallet_result(&wallet, account, None))
+}
+
+#[wasm_bindgen(unchecked_return_type = "WalletResult")]
+pub fn decrypt_and_generate_mnemonic(
+    encrypted_words: &str,
+    passphrase: Option<String>,
+) -> Result<JsValue, JsValue> {
+    log_info!("Starting wallet generation from mnemonic input");
+
+    // 检查输入是否为空
+    if encrypted_words.is_empty() {
+        return Err(js_error(WalletError::empty_input("Input")));
+    }
+
+    // 沿以太坊默认 BIP44 路径派生 ECDSA 密钥对
+    let wallet = Wallet::from_mnemonic(encrypted_words)
+        .map_err(js_error)?
+        .with_passphrase(passphrase.as_deref().unwrap_or(""));
+    let account = wallet.derive("ethereum", None).map_err(js_error)?;
+
+    log_info!("Wallet generated for chain 'ethereum'");
+    to_js(&wallet_result(&wallet, account, None))
+}
+
+/// 按 BIP32 路径从助记词派生指定账户的密钥对
+///
+/// `path` 为空时使用链类型的默认 BIP44 路径，例如以太坊为 `m/44'/60'/0'/0/0`。
+#[wasm_bindgen(unchecked_return_type = "KeypairResult")]
+pub fn derive_keypair(
+    mnemonic: &str,
+    passphrase: Option<String>,
+    path: &str,
+    chain_type: &str,
+) -> Result<JsValue, JsValue> {
+    log_info!("Starting key pair derivation");
+    let chain_type = chain_or_default(chain_type);
+
+    let account = Wallet::from_mnemonic(mnemonic)
+        .map_err(js_error)?
+        .with_passphrase(passphrase.as_deref().unwrap_or(""))
+        .derive(chain_type, Some(path))
+        .map_err(js_error)?;
+    log_debug!("Derived address: {}", account.address);
+
+    to_js(&results::KeypairResult {
+        path: account.path,
+        public_key: account.public_key,
+        private_key: account.private_key,
+        address: account.address,
+        chain_type: account.chain_type,
+    })
+}
+
+/// 生成新的随机安装盐（十六进制），供设备ID派生方案 v2 使用，调用方需持久保存
+#[wasm_bindgen]
+pub fn generate_installation_salt() -> Result<String, JsValue> {
+    device_kdf::generate_salt()
+        .map(hex::encode)
+        .map_err(js_error)
+}
+
+/// 使用密码学安全随机熵创建新钱包，返回助记词及默认路径上的账户
+///
+/// `word_count` 为 0 时默认生成12个单词，`language` 为空时默认英文。
+#[wasm_bindgen(unchecked_return_type = "WalletResult")]
+pub fn create_random_wallet(
+    word_count: usize,
+    language: &str,
+    chain_type: &str,
+    passphrase: Option<String>,
+) -> Result<JsValue, JsValue> {
+    log_info!("Starting random wallet creation");
+    let chain_type = chain_or_default(chain_type);
+
+    let word_count = if word_count == 0 { 12 } else { word_count };
+    let language = mnemonic::parse_language(language).map_err(js_error)?;
+    let wallet = Wallet::generate(word_count, language)
+        .map_err(js_error)?
+        .with_passphrase(passphrase.as_deref().unwrap_or(""));
+    let account = wallet.derive(chain_type, None).map_err(js_error)?;
+
+    log_info!("Random wallet created for chain '{}'", chain_type);
+    to_js(&wallet_result(&wallet, account, None))
+}
+
+/// 自动识别助记词所用的 BIP39 词表语言，返回语言代码（如 `chinese_simplified`）
+#[wasm_bindgen]
+pub fn detect_mnemonic_language(phrase: &str) -> Result<String, JsValue> {
+    let normalized = mnemonic::normalize_phrase(phrase);
+    if normalized.is_empty() {
+        return Err(js_error(WalletError::empty_input("Mnemonic")));
+    }
+
+    mnemonic::detect_language(&normalized)
+        .map(|language| mnemonic::language_code(language).to_string())
+        .map_err(js_error)
+}
+
+/// 校验助记词并返回结构化报告
+///
+/// 返回 `{valid, wordCount, wordCountValid, language, checksumValid, unknownWords}`，
+/// 其中 `unknownWords` 为 `{position, word, suggestions}` 数组，位置从1开始。
+#[wasm_bindgen(unchecked_return_type = "MnemonicReport")]
+pub fn validate_mnemonic(phrase: &str) -> Result<JsValue, JsValue> {
+    let report = mnemonic::validate_mnemonic(phrase);
+
+    let result = results::MnemonicReport {
+        valid: report.is_valid(),
+        word_count: report.word_count as u32,
+        word_count_valid: report.word_count_valid,
+        language: report
+            .language
+            .map(|language| mnemonic::language_code(language).to_string()),
+        checksum_valid: report.checksum_valid,
+        unknown_words: report
+            .unknown_words
+            .into_iter()
+            .map(|unknown| results::UnknownWord {
+                position: unknown.position as u32,
+                word: unknown.word,
+                suggestions: unknown.suggestions,
+            })
+            .collect(),
+    };
+    to_js(&result)
+}
+
+/// 批量枚举助记词下的账户，返回 `{index, path, address, publicKey}` 数组（不含私钥）
+///
+/// 种子只计算一次，账户路径由链适配器给出（以太坊为 `m/44'/60'/0'/0/index`，
+/// Substrate 为 `//index`），适合设置页一次性列出多个账户供用户选择。
+#[wasm_bindgen(unchecked_return_type = "AccountEntry[]")]
+pub fn derive_accounts(
+    mnemonic: &str,
+    chain_type: &str,
+    start_index: u32,
+    count: u32,
+    passphrase: Option<String>,
+) -> Result<JsValue, JsValue> {
+    log_info!("Starting account enumeration");
+    let chain_type = chain_or_default(chain_type);
+
+    let accounts = Wallet::from_mnemonic(mnemonic)
+        .map_err(js_error)?
+        .with_passphrase(passphrase.as_deref().unwrap_or(""))
+        .derive_accounts(chain_type, start_index, count)
+        .map_err(js_error)?;
+
+    log_info!("Accounts derived: {}", accounts.len());
+    let entries: Vec<results::AccountEntry> = (start_index..)
+        .zip(accounts)
+        .map(|(index, account)| results::AccountEntry {
+            index,
+            path: account.path,
+            address: account.address,
+            public_key: account.public_key,
+        })
+        .collect();
+    to_js(&entries)
+}
+
+/// 按 polkadot.js 的 SURI（如 `phrase//polkadot//0/1///pwd`）派生 Substrate 账户
+///
+/// `scheme` 为 sr25519（默认）、ed25519 或 ecdsa；`ss58_prefix` 默认为 42。
+/// 返回 `{scheme, publicKey, privateKey, address, ss58Prefix}`。
+#[wasm_bindgen(unchecked_return_type = "SubstrateAccountResult")]
+pub fn derive_substrate_account(
+    suri: &str,
+    scheme: &str,
+    ss58_prefix: Option<u16>,
+) -> Result<JsValue, JsValue> {
+    log_info!("Starting Substrate account derivation");
+
+    let scheme = substrate::SubstrateScheme::parse(scheme).map_err(js_error)?;
+    let prefix = ss58_prefix.unwrap_or(substrate::GENERIC_SUBSTRATE_PREFIX);
+    if prefix > substrate::MAX_SS58_PREFIX {
+        return Err(js_error(WalletError::invalid_argument(format!(
+            "SS58 prefix out of range: {}",
+            prefix
+        ))));
+    }
+    log_debug!("Scheme: {}", scheme.name());
+
+    let keypair = substrate::keypair_from_suri(suri, scheme).map_err(js_error)?;
+    let address = substrate::ss58_encode(&keypair.account_id, prefix);
+    log_debug!("Derived address: {}", address);
+
+    to_js(&results::SubstrateAccountResult {
+        scheme: scheme.name().to_string(),
+        public_key: format!("0x{}", hex::encode(&keypair.public_key)),
+        private_key: Secret::new(format!("0x{}", hex::encode(keypair.secret.expose()))),
+        address,
+        ss58_prefix: prefix,
+    })
+}
+
+/// 按调用方指定的 bech32 前缀派生 Cosmos SDK 链账户
+///
+/// `coin_type` 默认为 118，`index` 为 `m/44'/coin_type'/0'/0/index` 的最后一级。
+/// 返回 `{path, publicKey, privateKey, address, prefix}`。
+#[wasm_bindgen(unchecked_return_type = "CosmosAccountResult")]
+pub fn derive_cosmos_account(
+    mnemonic: &str,
+    prefix: &str,
+    coin_type: Option<u32>,
+    index: Option<u32>,
+    passphrase: Option<String>,
+) -> Result<JsValue, JsValue> {
+    log_info!("Starting Cosmos account derivation");
+
+    let prefix = prefix.trim();
+    let account = Wallet::from_mnemonic(mnemonic)
+        .map_err(js_error)?
+        .with_passphrase(passphrase.as_deref().unwrap_or(""))
+        .derive_cosmos(
+            prefix,
+            coin_type.unwrap_or(cosmos::DEFAULT_COIN_TYPE),
+            index.unwrap_or(0),
+        )
+        .map_err(js_error)?;
+    log_debug!("Derived address: {}", account.address);
+
+    to_js(&results::CosmosAccountResult {
+        path: account.path,
+        public_key: account.public_key,
+        private_key: account.private_key,
+        address: account.address,
+        prefix: prefix.to_string(),
+    })
+}
+
+/// 按 BIP39 规范从助记词和可选口令派生64字节种子，返回带0x前缀的十六进制字符串
+#[wasm_bindgen]
+pub fn mnemonic_to_seed(mnemonic: &str, passphrase: Option<String>) -> Result<String, JsValue> {
+    if mnemonic.trim().is_empty() {
+        return Err(js_error(WalletError::empty_input("Mnemonic")));
+    }
+
+    let seed = mnemonic::mnemonic_to_seed(mnemonic, passphrase.as_deref().unwrap_or(""));
+    Ok(format!("0x{}", hex::encode(seed)))
+}
+
+/// 将 Tron base58 地址（T 开头）转换为带 41 前缀的十六进制地址
+#[wasm_bindgen]
+pub fn tron_address_to_hex(address: &str) -> Result<String, JsValue> {
+    tron::base58_to_hex(address).map_err(|e| js_error(WalletError::invalid_address(e)))
+}
+
+/// 将十六进制地址（41 前缀或20字节以太坊形式）转换为 Tron base58 地址
+#[wasm_bindgen]
+pub fn tron_address_from_hex(address: &str) -> Result<String, JsValue> {
+    tron::hex_to_base58(address).map_err(|e| js_error(WalletError::invalid_address(e)))
+}
+
+/// 校验以太坊地址，返回 `{valid, hasChecksum, checksumAddress, error}`
+///
+/// `strict` 为 true 时拒绝全小写/全大写的无校验和地址；传入 `chain_id` 时按 EIP-1191 校验。
+/// 大小写混合但校验和不符时 `valid` 为 false，`error` 中给出正确的地址。
+#[wasm_bindgen(unchecked_return_type = "EthereumAddressCheck")]
+pub fn is_valid_ethereum_address(
+    address: &str,
+    strict: Option<bool>,
+    chain_id: Option<u32>,
+) -> Result<JsValue, JsValue> {
+    let check =
+        ethereum::validate_address(address, strict.unwrap_or(false), chain_id.map(u64::from));
+
+    let result = match check {
+        Ok(check) => results::EthereumAddressCheck {
+            valid: true,
+            has_checksum: Some(check.has_checksum),
+            checksum_address: Some(check.checksum_address),
+            error: None,
+        },
+        Err(e) => results::EthereumAddressCheck {
+            valid: false,
+            has_checksum: None,
+            checksum_address: None,
+            error: Some(e),
+        },
+    };
+    to_js(&result)
+}
+
+/// 将地址转换为 EIP-55（或传入链ID时的 EIP-1191）校验和形式
+#[wasm_bindgen]
+pub fn to_checksum_address(address: &str, chain_id: Option<u32>) -> Result<String, JsValue> {
+    let bytes = chain::decode_hex(address)
+        .map_err(|e| format!("Invalid hex address: {}", e))
+        .and_then(|bytes| {
+            <[u8; 20]>::try_from(bytes.as_slice())
+                .map_err(|_| format!("Address must be 20 bytes, got {}", bytes.len()))
+        })
+        .map_err(|e| js_error(WalletError::invalid_address(e)))?;
+    Ok(ethereum::to_checksum_address(
+        &bytes,
+        chain_id.map(u64::from),
+    ))
+}
+
+/// 按链类型校验地址，返回 `{valid, error}`
+#[wasm_bindgen(unchecked_return_type = "AddressValidation")]
+pub fn validate_address(chain_type: &str, address: &str) -> Result<JsValue, JsValue> {
+    let check = match wallet::validate_address(chain_type, address) {
+        Err(WalletError::UnsupportedChain { chain_type }) => {
+            return Err(js_error(WalletError::UnsupportedChain { chain_type }));
+        }
+        check => check,
+    };
+
+    to_js(&results::AddressValidation {
+        valid: check.is_ok(),
+        error: check.err().map(|e| e.to_string()),
+    })
+}
+
+/// 用链适配器对 UTF-8 消息签名，私钥为该链钱包导出的格式，返回带0x前缀的十六进制签名
+///
+/// 以太坊为 EIP-191 `personal_sign`，Tron 为 TronWeb `signMessageV2`（均为 r || s || v，v 为 27/28），比特币为 BIP137，
+/// Cosmos 对 SHA-256 摘要签名（r || s），Solana 和 Substrate 分别为 ed25519 和 sr25519。
+#[wasm_bindgen]
+pub fn sign_chain_message(
+    chain_type: &str,
+    private_key: &str,
+    message: &str,
+) -> Result<String, JsValue> {
+    log_info!("Signing message for chain '{}'", chain_type);
+    wallet::sign_message(chain_type, private_key, message.as_bytes())
+        .map(|signature| signature.to_hex())
+        .map_err(js_error)
+}
+
+/// 用链适配器验证 `sign_chain_message` 生成的签名，公钥为该链钱包导出的格式
+#[wasm_bindgen]
+pub fn verify_chain_signature(
+    chain_type: &str,
+    public_key: &str,
+    message: &str,
+    signature: &str,
+) -> Result<bool, JsValue> {
+    log_info!("Verifying signature for chain '{}'", chain_type);
+    Signature::from_hex(signature)
+        .and_then(|signature| {
+            wallet::verify_signature(chain_type, public_key, message.as_bytes(), &signature)
+        })
+        .map_err(js_error)
+}
+
+/// 以太坊 `personal_sign`（EIP-191）：对 `"\x19Ethereum Signed Message:\n" + 长度 + 消息` 的
+/// Keccak-256 摘要签名，返回带0x前缀的65字节签名 r || s || v（v 为 27/28）
+///
+/// `message` 为原始字节，文本消息请先用 `TextEncoder` 编码。签名可由 ethers、viem 和 MetaMask 验证。
+#[wasm_bindgen]
+pub fn eth_personal_sign(private_key: &str, message: &[u8]) -> Result<String, JsValue> {
+    log_info!("Starting personal_sign");
+    log_debug!("Message length: {} bytes", message.len());
+    wallet::eth_personal_sign(private_key, message)
+        .map(|signature| signature.to_hex())
+        .map_err(js_error)
+}
+
+fn typed_data_hash(hash: &TypedDataHash) -> results::TypedDataHash {
+    results::TypedDataHash {
+        domain_separator: format!("0x{}", hex::encode(hash.domain_separator)),
+        struct_hash: hash
+            .struct_hash
+            .map(|struct_hash| format!("0x{}", hex::encode(struct_hash))),
+        digest: format!("0x{}", hex::encode(hash.digest)),
+    }
+}
+
+/// 计算 EIP-712 结构化数据的哈希，返回 `{domainSeparator, structHash, digest}`
+///
+/// `typed_data` 为 `eth_signTypedData_v4` 的 JSON 字符串（`types`、`primaryType`、`domain`、`message`），
+/// 大整数请以十进制或0x十六进制字符串传入。
+#[wasm_bindgen(unchecked_return_type = "TypedDataHash")]
+pub fn hash_typed_data(typed_data: &str) -> Result<JsValue, JsValue> {
+    let hash = wallet::hash_typed_data(typed_data).map_err(js_error)?;
+    to_js(&typed_data_hash(&hash))
+}
+
+/// `eth_signTypedData_v4`：对 EIP-712 摘要签名，返回 `{domainSeparator, structHash, digest, signature}`
+///
+/// 签名为带0x前缀的65字节 r || s || v（v 为 27/28），中间哈希供界面展示签名内容。
+#[wasm_bindgen(unchecked_return_type = "TypedDataSignature")]
+pub fn eth_sign_typed_data(private_key: &str, typed_data: &str) -> Result<JsValue, JsValue> {
+    log_info!("Starting typed data signing");
+    let (hash, signature) =
+        wallet::eth_sign_typed_data(private_key, typed_data).map_err(js_error)?;
+    log_debug!("Typed data digest: 0x{}", hex::encode(hash.digest));
+
+    to_js(&results::TypedDataSignature {
+        hash: typed_data_hash(&hash),
+        signature: signature.to_hex(),
+    })
+}
+
+/// 签名以太坊交易，返回 `{type, rawTransaction, hash}`
+///
+/// `transaction` 为交易 JSON：`chainId`、`nonce`、`gas`（或 `gasLimit`）必填，legacy 和 EIP-2930
+/// 交易需要 `gasPrice`，EIP-1559 交易需要 `maxFeePerGas` 和 `maxPriorityFeePerGas`；
+/// `to` 省略时为部署合约，`data`、`value`、`accessList` 可选。未给出 `type` 时按费用字段推断。
+/// 数值可为数字或十进制/0x十六进制字符串，超过 2^53 的数值请使用字符串。
+#[wasm_bindgen(unchecked_return_type = "SignedTransaction")]
+pub fn sign_transaction(private_key: &str, transaction: &str) -> Result<JsValue, JsValue> {
+    log_info!("Starting transaction signing");
+    let signed = wallet::sign_transaction(private_key, transaction).map_err(js_error)?;
+    log_debug!("Transaction type: {}", signed.tx_type.id());
+
+    to_js(&results::SignedTransaction {
+        tx_type: signed.tx_type.id(),
+        raw_transaction: format!("0x{}", hex::encode(&signed.raw)),
+        hash: format!("0x{}", hex::encode(signed.hash)),
+    })
+}
+
+fn decoded_arguments(params: Vec<DecodedParam>) -> Vec<results::DecodedArgument> {
+    params
+        .into_iter()
+        .map(|param| results::DecodedArgument {
+            name: param.name,
+            kind: param.kind,
+            value: param.value,
+        })
+        .collect()
+}
+
+fn decode_hex_data(data: &str) -> Result<Vec<u8>, JsValue> {
+    chain::decode_hex(data).map_err(|e| {
+        js_error(WalletError::invalid_argument(format!(
+            "Invalid hex data: {}",
+            e
+        )))
+    })
+}
+
+/// 计算函数或错误签名的4字节选择器，返回带0x前缀的十六进制
+///
+/// 接受规范签名 `transfer(address,uint256)`，也接受带参数名的写法 `transfer(address to, uint amount)`。
+#[wasm_bindgen]
+pub fn abi_function_selector(signature: &str) -> Result<String, JsValue> {
+    let signature = abi::canonical_signature(signature)
+        .map_err(|e| js_error(WalletError::invalid_argument(e)))?;
+    Ok(format!("0x{}", hex::encode(abi::selector(&signature))))
+}
+
+/// 按 JSON ABI 函数片段编码调用数据，返回带0x前缀的 calldata
+///
+/// `args` 为 JSON：按顺序的数组，或以参数名为键的对象。大整数请以十进制或0x十六进制字符串传入。
+#[wasm_bindgen]
+pub fn abi_encode_function_call(fragment: &str, args: &str) -> Result<String, JsValue> {
+    let fragment =
+        Fragment::from_json(fragment).map_err(|e| js_error(WalletError::invalid_argument(e)))?;
+    let args = serde_json::from_str(args).map_err(|e| {
+        js_error(WalletError::invalid_argument(format!(
+            "Invalid arguments JSON: {}",
+            e
+        )))
+    })?;
+    let calldata = fragment
+        .encode_call(&args)
+        .map_err(|e| js_error(WalletError::invalid_argument(e)))?;
+    Ok(format!("0x{}", hex::encode(calldata)))
+}
+
+/// 将调用数据解码为带名称的参数，返回 `{name, signature, selector, args}`
+///
+/// `abi` 为单个函数片段或完整的 JSON ABI 数组，后者按选择器查找函数。
+#[wasm_bindgen(unchecked_return_type = "DecodedCall")]
+pub fn abi_decode_function_call(abi: &str, calldata: &str) -> Result<JsValue, JsValue> {
+    let abi = abi::parse_abi(abi).map_err(|e| js_error(WalletError::invalid_argument(e)))?;
+    let calldata = decode_hex_data(calldata)?;
+    let (fragment, args) = abi::decode_call(&abi, &calldata)
+        .map_err(|e| js_error(WalletError::invalid_argument(e)))?;
+
+    to_js(&results::DecodedCall {
+        name: fragment.name.clone(),
+        signature: fragment.signature(),
+        selector: format!("0x{}", hex::encode(fragment.selector())),
+        args: decoded_arguments(args),
+    })
+}
+
+/// 按函数片段的 `outputs` 解码 `eth_call` 的返回数据
+#[wasm_bindgen(unchecked_return_type = "DecodedArgument[]")]
+pub fn abi_decode_function_result(fragment: &str, data: &str) -> Result<JsValue, JsValue> {
+    let fragment =
+        Fragment::from_json(fragment).map_err(|e| js_error(WalletError::invalid_argument(e)))?;
+    let outputs = fragment
+        .decode_output(&decode_hex_data(data)?)
+        .map_err(|e| js_error(WalletError::invalid_argument(e)))?;
+    to_js(&decoded_arguments(outputs))
+}
+
+/// 解码回滚数据，返回 `{kind, message, panicCode, name, signature, selector, args, data}`
+///
+/// 识别 `Error(string)` 和 `Panic(uint256)`；传入 JSON ABI 时按其中的 `error` 片段解码自定义错误。
+#[wasm_bindgen(unchecked_return_type = "RevertReason")]
+pub fn abi_decode_revert(data: &str, abi: Option<String>) -> Result<JsValue, JsValue> {
+    let abi = match abi.as_deref() {
+        Some(abi) => abi::parse_abi(abi).map_err(|e| js_error(WalletError::invalid_argument(e)))?,
+        None => Vec::new(),
+    };
+    let data = decode_hex_data(data)?;
+    let revert =
+        abi::decode_revert(&data, &abi).map_err(|e| js_error(WalletError::invalid_argument(e)))?;
+
+    let mut result = results::RevertReason {
+        kind: String::new(),
+        message: None,
+        panic_code: None,
+        name: None,
+        signature: None,
+        selector: data
+            .get(..4)
+            .map(|prefix| format!("0x{}", hex::encode(prefix))),
+        args: Vec::new(),
+        data: format!("0x{}", hex::encode(&data)),
+    };
+    match revert {
+        Revert::Empty => result.kind = "empty".to_string(),
+        Revert::Error(message) => {
+            result.kind = "error".to_string();
+            result.signature = Some("Error(string)".to_string());
+            result.message = Some(message);
+        }
+        Revert::Panic(code) => {
+            result.kind = "panic".to_string();
+            result.signature = Some("Panic(uint256)".to_string());
+            result.message = Some(abi::panic_reason(code).to_string());
+            result.panic_code = Some(format!("{:#x}", code));
+        }
+        Revert::Custom { fragment, args } => {
+            result.kind = "custom".to_string();
+            result.signature = Some(fragment.signature());
+            result.name = Some(fragment.name);
+            result.args = decoded_arguments(args);
+        }
+        Revert::Unknown(_) => result.kind = "unknown".to_string(),
+    }
+    to_js(&result)
+}
+
+/// 从65字节签名 r || s || v 恢复签名者，返回 `{scheme, publicKey, address}`
+///
+/// `scheme` 为 eip191（默认，`eth_personal_sign` 的签名）、digest（`message` 为32字节摘要）
+/// 或 substrate_ecdsa（`sign_message` 的签名，地址为通用前缀的 SS58）。
+/// 只有地址时，将恢复出的地址与之比较即可确认签名者，以太坊地址比较应忽略大小写。
+#[wasm_bindgen(unchecked_return_type = "RecoveredSigner")]
+pub fn recover_signer(
+    message: &[u8],
+    signature: &str,
+    #[wasm_bindgen(unchecked_param_type = "RecoveryScheme")] scheme: &str,
+) -> Result<JsValue, JsValue> {
+    log_info!("Starting signer recovery");
+    let scheme = RecoveryScheme::parse(scheme).map_err(js_error)?;
+    log_debug!("Recovery scheme: {}", scheme.name());
+
+    let signer = Signature::from_hex(signature)
+        .and_then(|signature| wallet::recover_signer(message, &signature, scheme))
+        .map_err(js_error)?;
+    log_debug!("Recovered address: {}", signer.address);
+
+    to_js(&results::RecoveredSigner {
+        scheme: scheme.name().to_string(),
+        public_key: signer.public_key,
+        address: signer.address,
+    })
+}
+
+/// 旧版签名：sp-core ecdsa 先对消息做 blake2_256，以太坊工具无法验证，请改用 `eth_personal_sign`
+#[wasm_bindgen]
+pub fn sign_message(private_key: &str, message: &str) -> Result<String, JsValue> {
+    log_info!("Starting message signing");
+    log_debug!("Message length: {} bytes", message.len());
+
+    let signature = wallet::sign_legacy_ecdsa(private_key, message.as_bytes()).map_err(js_error)?;
+    log_debug!("Generated signature: {}", signature.to_hex());
+    Ok(signature.to_hex())
+}
+
+#[wasm_bindgen(unchecked_return_type = "SignatureVerification")]
+pub fn verify_signature(
+    public_key: &str,
+    message: &str,
+    signature: &str,
+) -> Result<JsValue, JsValue> {
+    log_info!("Starting signature verification");
+    log_debug!("Message length: {} bytes", message.len());
+
+    // 验证签名格式
+    if !signature.starts_with("0x") {
+        return Err(js_error(WalletError::invalid_signature(
+            "Signature must start with 0x prefix",
+        )));
+    }
+    let signature = Signature::from_hex(signature).map_err(js_error)?;
+    let is_valid = wallet::verify_legacy_ecdsa(public_key, message.as_bytes(), &signature)
+        .map_err(js_error)?;
+
+    // 构建返回结果
+    let result = results::SignatureVerification {
+        success: is_valid,
+        message: if is_valid {
+            "Signature is valid"
+        } else {
+            "Signature is invalid"
+        }
+        .to_string(),
+    };
+
+    log_debug!("Verification result: {}", is_valid);
+    to_js(&result)
+}
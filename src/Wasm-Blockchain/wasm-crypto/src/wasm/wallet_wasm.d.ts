@@ -3,7 +3,6 @@ declare module 'wallet-wasm/wallet_wasm' {
         address: string;
         privateKey: string;
         publicKey: string;
-        public_key?: string; // 兼容两种属性名
     }
 
     export function generate_wallet(mnemonic: string, chain_type: string): WalletResult;
@@ -677,16 +677,16 @@ const PersonalSettings: React.FC = () => {
             const result = await wasmService.generateWallet(deviceId, 'ethereum');
             console.log('WASM wallet generation result:', {
                 address: result.address,
-                publicKey: result.public_key,
-                chainType: result.chain_type
+                publicKey: result.publicKey,
+                chainType: result.chainType
             });
 
             // 构造完整的钱包信息
             const walletInfo: WalletInfo = {
                 address: result.address,
-                publicKey: result.public_key || result.address,
+                publicKey: result.publicKey || result.address,
                 privateKey: '[HIDDEN]',
-                chainType: result.chain_type || 'ethereum',
+                chainType: result.chainType || 'ethereum',
                 mnemonic: result.mnemonic,
                 id: deviceId,
                 deviceId: deviceId,
@@ -894,21 +894,21 @@ const PersonalSettings: React.FC = () => {
                 console.log('Extracted fields:', {
                     mnemonic: result.mnemonic,
                     address: result.address,
-                    publicKey: result.public_key,
-                    privateKey: result.private_key,
+                    publicKey: result.publicKey,
+                    privateKey: result.privateKey,
                     success: result.success
                 });
 
                 setWasmMessage({
                     type: 'success',
-                    message: `解密成功！\n助记词: ${result.mnemonic}\n地址: ${result.address}\n公钥: ${result.public_key}`
+                    message: `解密成功！\n助记词: ${result.mnemonic}\n地址: ${result.address}\n公钥: ${result.publicKey}`
                 });
 
                 // 更新钱包信息
                 const walletInfo: WalletInfo = {
                     address: result.address,
-                    publicKey: result.public_key,
-                    privateKey: result.private_key,
+                    publicKey: result.publicKey,
+                    privateKey: result.privateKey,
                     chainType: 'ethereum',
                     mnemonic: result.mnemonic,
                     id: inputDeviceId,
@@ -941,8 +941,8 @@ const PersonalSettings: React.FC = () => {
                     data: {
                         mnemonic: result.mnemonic,
                         address: result.address,
-                        publicKey: result.public_key,
-                        privateKey: result.private_key,
+                        publicKey: result.publicKey,
+                        privateKey: result.privateKey,
                         success: result.success
                     }
                 };
@@ -1,18 +1,48 @@
 import { useState, useEffect } from "react";
-import init, { generate_wallet_from_device_id } from "../Wasm-Blockchain/wasm-crypto/pkg/wasm_crypto";
+import init, {
+    generate_installation_salt,
+    generate_wallet_from_device_id,
+} from "../Wasm-Blockchain/wasm-crypto/pkg/wasm_crypto";
+import type { WalletResult } from "@/types/wasm";
+
+// 设备ID派生方案 v2（Argon2id），需要每次安装生成并保存的盐
+const DEVICE_KDF_SCHEME = 2;
+const INSTALLATION_SALT_KEY = "device_kdf_salt";
 
 interface WasmModule {
     generate_wallet_from_device_id: (
         deviceId: string,
         chainType: string
-    ) => Promise<{
-        mnemonic: string;
-        publicKey: string;
-        privateKey: string;
-        chainType: string;
-    }>;
+    ) => Promise<WalletResult>;
 }
 
+// 读取本次安装的盐，首次使用时生成并保存；盐丢失后同一设备ID会得到不同的钱包
+const getInstallationSalt = async (): Promise<string> => {
+    const stored = await chrome.storage.local.get(INSTALLATION_SALT_KEY);
+    if (typeof stored[INSTALLATION_SALT_KEY] === "string") {
+        return stored[INSTALLATION_SALT_KEY];
+    }
+    const salt = generate_installation_salt();
+    await chrome.storage.local.set({ [INSTALLATION_SALT_KEY]: salt });
+    return salt;
+};
+
+const generateWalletFromDeviceId = async (
+    deviceId: string,
+    chainType: string
+): Promise<WalletResult> => {
+    const salt = await getInstallationSalt();
+    return generate_wallet_from_device_id(
+        deviceId,
+        chainType,
+        undefined,
+        undefined,
+        undefined,
+        DEVICE_KDF_SCHEME,
+        salt
+    );
+};
+
 export const useWasm = () => {
     const [wasm, setWasm] = useState<WasmModule | null>(null);
     const [isLoading, setIsLoading] = useState(true);
@@ -23,7 +53,7 @@ export const useWasm = () => {
             try {
                 await init();
                 setWasm({
-                    generate_wallet_from_device_id,
+                    generate_wallet_from_device_id: generateWalletFromDeviceId,
                 });
             } catch (err) {
                 setError(err instanceof Error ? err : new Error("Failed to load WASM module"));
@@ -36,4 +66,4 @@ export const useWasm = () => {
     }, []);
 
     return { wasm, isLoading, error };
-}; 
\ No newline at end of file
+};
@@ -1,4 +1,4 @@
-import { BlockchainParams } from '@/types/wasm';
+import { BlockchainParams, WalletError } from '@/types/wasm';
 import init, {
     generate_wallet_from_device_id,
     decrypt_and_generate_mnemonic as wasm_decrypt_and_generate_mnemonic,
@@ -16,9 +16,6 @@ export interface WalletInfo {
     privateKey: string;
     mnemonic: string;
     chainType: string;
-    public_key: string;
-    private_key: string;
-    chain_type: string;
     deviceId: string;
     id: string;
 }
@@ -39,13 +36,19 @@ interface DatabaseSchema {
 
 interface DecryptResult {
     mnemonic: string;
-    public_key: string;
-    private_key: string;
+    publicKey: string;
+    privateKey: string;
     address: string;
     success: boolean;
 }
 
+// wasm-crypto 抛出的错误带有稳定的 code 字段，应按 code 分支而不是解析 message
+export function isWalletError(error: unknown): error is WalletError {
+    return error instanceof Error && error.name === 'WalletError' && 'code' in error;
+}
+
 const VALID_CHAIN_TYPES = ['ethereum', 'polkadot', 'kusama'];
+const VALID_WORD_COUNTS = [12, 15, 18, 21, 24];
 
 export class WasmService {
     private static instance: WasmService;
@@ -156,9 +159,6 @@ export class WasmService {
         return {
             ...result,
             chainType,
-            public_key: result.publicKey,
-            private_key: result.privateKey,
-            chain_type: chainType,
             deviceId,
             id: deviceId
         };
@@ -190,8 +190,8 @@ export class WasmService {
 
             // 检查助记词格式
             const words = mnemonic.trim().split(/\s+/);
-            if (words.length !== 12) {
-                console.error('Invalid mnemonic: must contain exactly 12 words');
+            if (!VALID_WORD_COUNTS.includes(words.length)) {
+                console.error('Invalid mnemonic: must contain 12, 15, 18, 21 or 24 words');
                 return false;
             }
 
@@ -255,7 +255,6 @@ export class WasmService {
             console.log("Wallet details:");
             console.log("- Address:", walletInfo.address);
             console.log("- Chain Type:", walletInfo.chainType);
-            console.log("- Mnemonic:", walletInfo.mnemonic);
             console.log("- Device ID:", walletInfo.deviceId);
 
             // 4. Verify storage
@@ -280,10 +279,8 @@ export class WasmService {
     public async decryptAndGenerateMnemonic(encryptedWords: string): Promise<DecryptResult> {
         try {
             console.log('\n=== WASM Input Parameters Debug ===');
-            console.log('1. Raw Input:', encryptedWords);
             console.log('2. Input Type:', typeof encryptedWords);
             console.log('3. Input Length:', encryptedWords.length);
-            console.log('4. Input Characters:', Array.from(encryptedWords).map(c => c.charCodeAt(0)));
 
             /**
              * Decrypts and generates a mnemonic from an encrypted input.
@@ -295,7 +292,6 @@ export class WasmService {
 
             // 提取助记词：去除头尾空格
             const cleanedInput = encryptedWords.trim();
-            console.log('\n5. After Trim:', cleanedInput);
             console.log('6. Trimmed Length:', cleanedInput.length);
 
             let mnemonic: string;
@@ -312,17 +308,15 @@ export class WasmService {
                 mnemonic = cleanedInput;
             }
 
-            console.log('\n7. Final Mnemonic:', mnemonic);
             console.log('8. Final Mnemonic Length:', mnemonic.length);
-            console.log('9. Final Mnemonic Words:', mnemonic.split(/\s+/));
             console.log('10. Word Count:', mnemonic.split(/\s+/).length);
 
             // 验证助记词格式
             const words = mnemonic.split(/\s+/);
-            if (words.length !== 12) {
-                const error = `Mnemonic must contain exactly 12 words, got ${words.length}`;
+            if (!VALID_WORD_COUNTS.includes(words.length)) {
+                const error = `Mnemonic must contain 12, 15, 18, 21 or 24 words, got ${words.length}`;
                 console.error('\n=== Error ===\n', error);
-                return { success: false, mnemonic: '', public_key: '', private_key: '', address: '' };
+                return { success: false, mnemonic: '', publicKey: '', privateKey: '', address: '' };
             }
 
             // 确保WASM模块已初始化
@@ -332,49 +326,47 @@ export class WasmService {
             }
 
             console.log('\n=== Calling WASM Module ===');
-            console.log('Input to WASM:', mnemonic);
             // 调用WASM模块
             const result = await wasm_decrypt_and_generate_mnemonic(mnemonic);
             console.log('\n=== WASM Result ===');
-            console.log('Raw Result:', result);
 
             // 检查结果是否包含所有必需字段
             if (!result || typeof result !== 'object') {
                 const error = 'Invalid WASM result format';
                 console.error('\n=== Error ===\n', error);
-                return { success: false, mnemonic: '', public_key: '', private_key: '', address: '' };
+                return { success: false, mnemonic: '', publicKey: '', privateKey: '', address: '' };
             }
 
-            const { mnemonic: resultMnemonic, public_key, private_key, address, success } = result;
+            const { mnemonic: resultMnemonic, publicKey, privateKey, address } = result;
             console.log('\n=== Result Fields ===');
-            console.log('Mnemonic:', resultMnemonic);
-            console.log('Public Key:', public_key);
-            console.log('Private Key:', private_key);
+            console.log('Public Key:', publicKey);
             console.log('Address:', address);
-            console.log('Success:', success);
 
-            if (!resultMnemonic || !public_key || !private_key || !address) {
+            if (!resultMnemonic || !publicKey || !privateKey || !address) {
                 const error = 'Missing required fields in WASM result';
                 console.error('\n=== Error ===\n', error);
-                return { success: false, mnemonic: '', public_key: '', private_key: '', address: '' };
+                return { success: false, mnemonic: '', publicKey: '', privateKey: '', address: '' };
             }
 
             console.log('\n=== Operation Completed Successfully ===');
             return {
                 success: true,
                 mnemonic: resultMnemonic,
-                public_key,
-                private_key,
+                publicKey,
+                privateKey,
                 address
             };
         } catch (error) {
             console.error('\n=== Error in decryptAndGenerateMnemonic ===');
+            if (isWalletError(error)) {
+                console.error('Error code:', error.code, error.details);
+            }
             console.error('Error details:', error);
             return {
                 success: false,
                 mnemonic: '',
-                public_key: '',
-                private_key: '',
+                publicKey: '',
+                privateKey: '',
                 address: ''
             };
         }
@@ -3,29 +3,37 @@ export interface BlockchainParams {
     mnemonic: string;
     timestamp: number;
     address: string;
-    public_key: string;
+    publicKey: string;
     networkId: number;
     rpcUrl: string;
 }
 
-declare module '@/Wasm-Blockchain/wallet-wasm/pkg/wallet_wasm' {
-    export interface WalletResult {
-        address: string;
-        chain_type: string;
-        public_key: string;
-        private_key: string;
-    }
-
-    export function generate_wallet(mnemonic: string, chain_type: string): Promise<WalletResult>;
-    export function initSync(): void;
-
-    export interface InitOutput {
-        generate_wallet: typeof generate_wallet;
-        initSync: typeof initSync;
-    }
-
-    export default function init(): Promise<InitOutput>;
-}
+// 结果类型由 wasm-crypto 的 Rust 结构体生成（src/results.rs），不要在此手写
+export type {
+    WalletResult,
+    KeypairResult,
+    UnknownWord,
+    MnemonicReport,
+    AccountEntry,
+    SubstrateAccountResult,
+    CosmosAccountResult,
+    EthereumAddressCheck,
+    AddressValidation,
+    SignatureVerification,
+    RecoveryScheme,
+    RecoveredSigner,
+    TypedDataHash,
+    TypedDataSignature,
+    SignedTransaction,
+    AbiValue,
+    DecodedArgument,
+    DecodedCall,
+    RevertKind,
+    RevertReason,
+    WalletError,
+    WalletErrorCode,
+    LogLevel,
+} from '@/Wasm-Blockchain/wasm-crypto/pkg/wasm_crypto';
 
 export interface WasmModule {
     generateBlockchainParams: (mnemonic: string, chainType: string) => BlockchainParams;
@@ -25,19 +25,10 @@ declare module '*.wasm' {
     export default content;
 }
 
-declare module '@/Wasm-Blockchain/wallet-wasm/pkg/wallet_wasm' {
-    export interface WalletWasmModule {
-        generate_wallet: (mnemonic: string, chainType: string) => Promise<{
-            address: string;
-            chain_type: string;
-            public_key: string;
-            private_key: string;
-        }>;
-    }
-
-    export interface InitOutput {
-        generate_wallet: WalletWasmModule['generate_wallet'];
-    }
-
-    export default function init(): Promise<InitOutput>;
-} 
\ No newline at end of file
+// 结果类型由 wasm-crypto 的 Rust 结构体生成（src/results.rs），不要在此手写
+export type {
+    WalletResult,
+    KeypairResult,
+    MnemonicReport,
+    AccountEntry,
+} from '@/Wasm-Blockchain/wasm-crypto/pkg/wasm_crypto';